@@ -0,0 +1,99 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use one_or_many::{OneOrMany, OneOrManyInline};
+use smallvec::SmallVec;
+
+const SIZES: [usize; 4] = [1, 2, 4, 16];
+
+fn push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push");
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new("OneOrMany", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = OneOrMany::new();
+                (0..size).for_each(|d| list.push(black_box(d)));
+                list
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("OneOrManyInline<4>", size),
+            &size,
+            |b, &size| {
+                b.iter(|| {
+                    let mut list = OneOrManyInline::<_, 4>::new();
+                    (0..size).for_each(|d| list.push(black_box(d)));
+                    list
+                })
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("SmallVec<4>", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut list = SmallVec::<[_; 4]>::new();
+                (0..size).for_each(|d| list.push(black_box(d)));
+                list
+            })
+        });
+    }
+    group.finish();
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+    for size in SIZES {
+        let list = (0..size).collect::<OneOrMany<_>>();
+        group.bench_with_input(BenchmarkId::new("OneOrMany", size), &list, |b, list| {
+            b.iter(|| list.iter().sum::<usize>())
+        });
+
+        let list = (0..size).collect::<OneOrManyInline<_, 4>>();
+        group.bench_with_input(
+            BenchmarkId::new("OneOrManyInline<4>", size),
+            &list,
+            |b, list| b.iter(|| list.iter().sum::<usize>()),
+        );
+
+        let list = (0..size).collect::<SmallVec<[_; 4]>>();
+        group.bench_with_input(BenchmarkId::new("SmallVec<4>", size), &list, |b, list| {
+            b.iter(|| list.iter().sum::<usize>())
+        });
+    }
+    group.finish();
+}
+
+fn into_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("into_iter");
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new("OneOrMany", size), &size, |b, &size| {
+            b.iter_batched(
+                || (0..size).collect::<OneOrMany<_>>(),
+                |list| list.into_iter().sum::<usize>(),
+                criterion::BatchSize::SmallInput,
+            )
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("OneOrManyInline<4>", size),
+            &size,
+            |b, &size| {
+                b.iter_batched(
+                    || (0..size).collect::<OneOrManyInline<_, 4>>(),
+                    |list| list.into_iter().sum::<usize>(),
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("SmallVec<4>", size), &size, |b, &size| {
+            b.iter_batched(
+                || (0..size).collect::<SmallVec<[_; 4]>>(),
+                |list| list.into_iter().sum::<usize>(),
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, push, iterate, into_iter);
+criterion_main!(benches);
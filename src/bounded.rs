@@ -0,0 +1,235 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Iter, IterMut, OneOrMany, OneOrManyIntoIter};
+
+/// A [`OneOrMany`] holding at least `MIN` and at most `MAX` elements
+///
+/// Every constructor and mutation checks the bound, and reports a [`CardinalityError`] instead of leaving it. A `MIN`
+/// greater than `MAX` fails to compile once a `Between` is constructed.
+///
+/// This only hands out shared access to the inner [`OneOrMany`], use [`Between::into_inner`] to get it back.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Between<T, const MIN: usize, const MAX: usize> {
+    inner: OneOrMany<T>,
+}
+
+/// A [`Between`] holding no more than `N` elements
+pub type AtMost<T, const N: usize> = Between<T, 0, N>;
+
+/// A [`Between`] holding exactly `N` elements
+pub type Exactly<T, const N: usize> = Between<T, N, N>;
+
+/// The number of elements was outside of the bounds of a [`Between`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CardinalityError {
+    pub min: usize,
+    pub max: usize,
+    pub actual: usize,
+}
+
+impl core::fmt::Display for CardinalityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self { min, max, actual } = *self;
+        let elements = |n| if n == 1 { "element" } else { "elements" };
+        match (min, max) {
+            (min, max) if min == max => write!(f, "expected exactly {min} {}", elements(min))?,
+            (0, max) => write!(f, "expected at most {max} {}", elements(max))?,
+            (min, max) => write!(f, "expected between {min} and {max} elements")?,
+        }
+        write!(f, ", found {actual}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CardinalityError {}
+
+impl<T, const MIN: usize, const MAX: usize> Between<T, MIN, MAX> {
+    /// Checks that `inner` has between `MIN` and `MAX` elements
+    pub fn new(inner: OneOrMany<T>) -> Result<Self, CardinalityError> {
+        Self::check(inner.len())?;
+        Ok(Self { inner })
+    }
+
+    pub fn into_inner(self) -> OneOrMany<T> {
+        self.inner
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
+    /// Appends an element, unless that would go over `MAX`
+    ///
+    /// Without the `alloc` feature this can't go over four elements either, whatever `MAX` is, and the error then
+    /// reports a `max` of 4.
+    pub fn push(&mut self, item: T) -> Result<(), CardinalityError> {
+        let actual = self.len() + 1;
+        Self::check(actual)?;
+        // without `alloc` a `OneOrMany` runs out of room at four elements
+        self.inner.try_push(item).map_err(|_| CardinalityError {
+            max: actual - 1,
+            ..Self::error(actual)
+        })
+    }
+
+    /// Removes the last element, unless that would go under `MIN`
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len() <= MIN {
+            return None;
+        }
+        self.inner.pop()
+    }
+
+    /// Appends every element of `iter`, unless that would go over `MAX`
+    ///
+    /// On error nothing is appended. `iter` is only consumed up to the first element that doesn't fit, so the error
+    /// then reports one more element than fits rather than the length of `iter`.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CardinalityError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = self.len();
+        let was_single = matches!(self.inner, OneOrMany::Single(..));
+        let mut iter = iter.into_iter();
+        let result = loop {
+            match iter.next() {
+                Some(item) => {
+                    if let Err(err) = self.push(item) {
+                        break Err(err);
+                    }
+                }
+                None => break Self::check(self.len()),
+            }
+        };
+
+        if result.is_err() {
+            self.inner.truncate(len);
+            if was_single {
+                self.inner.normalize();
+            }
+        }
+        result
+    }
+
+    fn check(actual: usize) -> Result<(), CardinalityError> {
+        const { assert!(MIN <= MAX, "`MIN` cannot be greater than `MAX`") }
+        if (MIN..=MAX).contains(&actual) {
+            return Ok(());
+        }
+        Err(Self::error(actual))
+    }
+
+    const fn error(actual: usize) -> CardinalityError {
+        CardinalityError {
+            min: MIN,
+            max: MAX,
+            actual,
+        }
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> core::ops::Deref for Between<T, MIN, MAX> {
+    type Target = OneOrMany<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<OneOrMany<T>> for Between<T, MIN, MAX> {
+    fn as_ref(&self) -> &OneOrMany<T> {
+        &self.inner
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for Between<T, MIN, MAX> {
+    fn as_ref(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<T, U, const MIN: usize, const MAX: usize> PartialEq<[U]> for Between<T, MIN, MAX>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.inner.as_slice() == other
+    }
+}
+
+impl<T, U, const MIN: usize, const MAX: usize, const N: usize> PartialEq<[U; N]>
+    for Between<T, MIN, MAX>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.inner.as_slice() == other
+    }
+}
+
+/// Panics if this would go over `MAX`, see [`Between::try_extend`] for a fallible version
+impl<T, const MIN: usize, const MAX: usize> Extend<T> for Between<T, MIN, MAX> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if let Err(err) = self.try_extend(iter) {
+            panic!("{err}")
+        }
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> TryFrom<OneOrMany<T>> for Between<T, MIN, MAX> {
+    type Error = CardinalityError;
+
+    fn try_from(inner: OneOrMany<T>) -> Result<Self, Self::Error> {
+        Self::new(inner)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for Between<T, MIN, MAX> {
+    type Error = CardinalityError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(OneOrMany::from(vec))
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<Between<T, MIN, MAX>> for OneOrMany<T> {
+    fn from(bounded: Between<T, MIN, MAX>) -> Self {
+        bounded.inner
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for Between<T, MIN, MAX> {
+    type Item = T;
+    type IntoIter = OneOrManyIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a Between<T, MIN, MAX> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a mut Between<T, MIN, MAX> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,270 @@
+//! Clap support for [`OneOrMany`]
+//!
+//! [`OneOrMany`] implements [`ValueParserFactory`] for any `T: FromStr`, so a derived argument can hold
+//! [`OneOrMany`]s directly. Each value is split on `,` and parsed piece by piece, keeping the shape that was typed:
+//! `--tag a` gives a `Single` and `--tag a,b` gives a `Many`. Pieces are trimmed of whitespace and empty ones are
+//! skipped, the same as [`OneOrMany::parse_with`]. Use [`OneOrManyValueParser`] to pick another delimiter or another
+//! parser for the elements.
+//!
+//! The derive reads a field typed as a bare [`OneOrMany`] from exactly one occurrence, like any type that isn't an
+//! `Option` or a `Vec`. To accept zero, one or many occurrences (`--tag a --tag b,c`) in a single field, flatten a
+//! [`Merged`] instead, naming its argument with a [`MergedArg`]:
+//!
+//! ```ignore
+//! struct Tag;
+//!
+//! impl MergedArg for Tag {
+//!     fn arg() -> clap::Arg {
+//!         clap::Arg::new("tag").long("tag")
+//!     }
+//! }
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!     #[command(flatten)]
+//!     tag: Merged<String, Tag>,
+//! }
+//! ```
+//!
+//! With the builder API, [`get_merged`] reads every occurrence of an appended argument the same way.
+use std::{boxed::Box, ffi::OsStr, marker::PhantomData, str::FromStr, vec::Vec};
+
+use ::clap::{
+    builder::{PossibleValue, TypedValueParser, ValueParserFactory},
+    error::{Error, ErrorKind},
+    parser::MatchesError,
+    Arg, ArgAction, ArgMatches, Args, Command, FromArgMatches,
+};
+
+use crate::{parse::split_trimmed, OneOrMany};
+
+/// Parses a value into a [`OneOrMany`] by splitting it on a delimiter, and parsing each piece with `P`
+#[derive(Copy, Clone, Debug)]
+pub struct OneOrManyValueParser<P> {
+    inner: P,
+    delimiter: Option<char>,
+}
+
+impl<P> OneOrManyValueParser<P>
+where
+    P: TypedValueParser,
+{
+    /// Splits on `,`
+    pub const fn new(inner: P) -> Self {
+        Self {
+            inner,
+            delimiter: Some(','),
+        }
+    }
+
+    /// Splits on `delimiter`, or not at all if it is `None`
+    ///
+    /// Without a delimiter the value is parsed as it is, and only an empty value gives an empty `Single`.
+    pub const fn delimiter(mut self, delimiter: Option<char>) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+}
+
+impl<P> TypedValueParser for OneOrManyValueParser<P>
+where
+    P: TypedValueParser,
+{
+    type Value = OneOrMany<P::Value>;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let Some(delimiter) = self.delimiter else {
+            if value.is_empty() {
+                return Ok(OneOrMany::new());
+            }
+            return self.inner.parse_ref(cmd, arg, value).map(OneOrMany::from);
+        };
+
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+        let mut delimiter_buf = [0; 4];
+        let mut pieces = split_trimmed(value, delimiter.encode_utf8(&mut delimiter_buf));
+        let Some(first) = pieces.next() else {
+            return Ok(OneOrMany::new());
+        };
+        let first = self.inner.parse_ref(cmd, arg, OsStr::new(first))?;
+
+        let mut rest = pieces.peekable();
+        if rest.peek().is_none() {
+            return Ok(OneOrMany::from(first));
+        }
+
+        let mut many = Vec::with_capacity(2);
+        many.push(first);
+        for piece in rest {
+            many.push(self.inner.parse_ref(cmd, arg, OsStr::new(piece))?);
+        }
+        Ok(OneOrMany::Many(many.into()))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.inner.possible_values()
+    }
+}
+
+impl<T> ValueParserFactory for OneOrMany<T>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+{
+    type Parser = OneOrManyValueParser<fn(&str) -> Result<T, T::Err>>;
+
+    fn value_parser() -> Self::Parser {
+        OneOrManyValueParser::new(T::from_str)
+    }
+}
+
+/// Concatenates the values of every occurrence of an argument
+///
+/// A single occurrence keeps its shape, and several are concatenated into a `Many`. No occurrence at all gives an
+/// empty `Single`.
+pub fn merge<T, I>(occurrences: I) -> OneOrMany<T>
+where
+    I: IntoIterator<Item = OneOrMany<T>>,
+{
+    let mut occurrences = occurrences.into_iter();
+    let Some(mut merged) = occurrences.next() else {
+        return OneOrMany::new();
+    };
+
+    for next in occurrences {
+        let mut many = merged.into_vec();
+        many.extend(next);
+        merged = OneOrMany::Many(many.into());
+    }
+    merged
+}
+
+/// Reads every occurrence of `id`, parsed with a [`OneOrManyValueParser`], into one [`OneOrMany`], see [`merge`]
+pub fn get_merged<T>(matches: &ArgMatches, id: &str) -> Result<OneOrMany<T>, MatchesError>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let occurrences = matches.try_get_occurrences::<OneOrMany<T>>(id)?;
+    Ok(merge(occurrences.into_iter().flatten().flatten().cloned()))
+}
+
+/// Names the argument of a [`Merged`] field
+pub trait MergedArg {
+    /// The argument to read, [`Merged`] sets its action and value parser
+    fn arg() -> Arg;
+
+    /// What each value is split on, see [`OneOrManyValueParser::delimiter`]
+    fn delimiter() -> Option<char> {
+        Some(',')
+    }
+}
+
+/// Every occurrence of the argument named by `A`, merged into one [`OneOrMany`]
+///
+/// Flatten this into a derived parser, see the [module docs](self). No occurrence at all gives an empty `Single`, a
+/// single occurrence keeps its shape and several are concatenated into a `Many`, see [`merge`].
+pub struct Merged<T, A> {
+    inner: OneOrMany<T>,
+    _arg: PhantomData<fn() -> A>,
+}
+
+impl<T, A> Merged<T, A> {
+    pub const fn new(inner: OneOrMany<T>) -> Self {
+        Self {
+            inner,
+            _arg: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> OneOrMany<T> {
+        self.inner
+    }
+}
+
+impl<T, A> Default for Merged<T, A> {
+    fn default() -> Self {
+        Self::new(OneOrMany::new())
+    }
+}
+
+impl<T, A> Clone for Merged<T, A>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<T, A> core::fmt::Debug for Merged<T, A>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Merged").field(&self.inner).finish()
+    }
+}
+
+impl<T, A> core::ops::Deref for Merged<T, A> {
+    type Target = OneOrMany<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, A> core::ops::DerefMut for Merged<T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T, A> From<Merged<T, A>> for OneOrMany<T> {
+    fn from(merged: Merged<T, A>) -> Self {
+        merged.into_inner()
+    }
+}
+
+impl<T, A> FromArgMatches for Merged<T, A>
+where
+    T: Clone + Send + Sync + 'static,
+    A: MergedArg,
+{
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, Error> {
+        let arg = A::arg();
+        get_merged(matches, arg.get_id().as_str())
+            .map(Self::new)
+            .map_err(|err| Error::raw(ErrorKind::ValueValidation, err))
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), Error> {
+        if matches.contains_id(A::arg().get_id().as_str()) {
+            *self = Self::from_arg_matches(matches)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, A> Args for Merged<T, A>
+where
+    T: FromStr + Clone + Send + Sync + 'static,
+    T::Err: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    A: MergedArg,
+{
+    fn augment_args(cmd: Command) -> Command {
+        let parser = OneOrMany::<T>::value_parser().delimiter(A::delimiter());
+        cmd.arg(A::arg().action(ArgAction::Append).value_parser(parser))
+    }
+
+    fn augment_args_for_update(cmd: Command) -> Command {
+        Self::augment_args(cmd)
+    }
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+
+use crate::{Between, OneOrMany, OneOrManyInline, OneOrMore};
+
+/// Displays the elements of a [`OneOrMany`] joined by separators, see [`OneOrMany::display_with`]
+#[derive(Debug)]
+pub struct DisplayWith<'a, T> {
+    items: &'a [T],
+    separator: &'a str,
+    last_separator: &'a str,
+}
+
+impl<T> Clone for DisplayWith<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DisplayWith<'_, T> {}
+
+impl<T> OneOrMany<T> {
+    /// Displays the elements joined by `separator`, with `last_separator` before the last one
+    ///
+    /// `list.display_with(", ", " and ")` prints `a, b and c`.
+    pub fn display_with<'a>(
+        &'a self,
+        separator: &'a str,
+        last_separator: &'a str,
+    ) -> DisplayWith<'a, T> {
+        DisplayWith {
+            items: self.as_slice(),
+            separator,
+            last_separator,
+        }
+    }
+}
+
+impl<T, const N: usize> OneOrManyInline<T, N> {
+    /// Displays the elements joined by `separator`, with `last_separator` before the last one, see
+    /// [`OneOrMany::display_with`]
+    pub fn display_with<'a>(
+        &'a self,
+        separator: &'a str,
+        last_separator: &'a str,
+    ) -> DisplayWith<'a, T> {
+        DisplayWith {
+            items: self.as_slice(),
+            separator,
+            last_separator,
+        }
+    }
+}
+
+impl<T> fmt::Display for DisplayWith<'_, T>
+where
+    T: fmt::Display,
+{
+    // the formatting options apply to each element
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.items.len();
+        for (index, item) in self.items.iter().enumerate() {
+            match index {
+                0 => {}
+                index if index + 1 == len => f.write_str(self.last_separator)?,
+                _ => f.write_str(self.separator)?,
+            }
+            fmt::Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints a single element plainly, and many elements joined by `, `
+///
+/// Nothing is printed for an empty list. See [`OneOrMany::display_with`] for other separators.
+impl<T> fmt::Display for OneOrMany<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(", ", ", "), f)
+    }
+}
+
+impl<T, const N: usize> fmt::Display for OneOrManyInline<T, N>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(", ", ", "), f)
+    }
+}
+
+impl<T> fmt::Display for OneOrMore<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_one_or_many(), f)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> fmt::Display for Between<T, MIN, MAX>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
@@ -0,0 +1,515 @@
+use core::{iter::FusedIterator, ops::RangeBounds};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::TryReserveError, vec::Vec};
+
+#[cfg(not(feature = "alloc"))]
+use crate::inline::{InlineBuf, InlineIntoIter};
+use crate::{Drain, Iter, IterMut};
+
+// a `Single` spills into a `Many` with the smallest capacity a `Vec` grows to, rather than exactly two
+//
+// without `alloc` this is all the room a `Many` has
+pub(crate) const SPILL_CAPACITY: usize = 4;
+
+#[cfg(feature = "alloc")]
+type Buf<T> = Vec<T>;
+#[cfg(not(feature = "alloc"))]
+type Buf<T> = InlineBuf<T, SPILL_CAPACITY>;
+
+/// The elements of a [`OneOrMany::Many`](crate::OneOrMany::Many)
+///
+/// With the `alloc` feature this is a `Vec` on the heap, and converts to and from one. Without it the elements are
+/// stored inline, with room for four of them. Either way it has the same slice-like methods, so code matching on a
+/// `Many` compiles the same way with or without `alloc`.
+pub struct Heap<T> {
+    buf: Buf<T>,
+}
+
+impl<T> Default for Heap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Heap<T> {
+    pub const fn new() -> Self {
+        Self { buf: Buf::new() }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    // the `Many` a `Single` spills into
+    pub(crate) fn spill() -> Self {
+        #[cfg(feature = "alloc")]
+        return Self::with_capacity(SPILL_CAPACITY);
+        #[cfg(not(feature = "alloc"))]
+        return Self::new();
+    }
+
+    pub const fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many elements this can hold without allocating, without the `alloc` feature this is always four
+    pub fn capacity(&self) -> usize {
+        #[cfg(feature = "alloc")]
+        return self.buf.capacity();
+        #[cfg(not(feature = "alloc"))]
+        return SPILL_CAPACITY;
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.buf.reserve_exact(additional)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve(additional)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink_to_fit()
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.buf
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.buf.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.buf.as_mut_slice()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.as_mut_slice())
+    }
+
+    /// Appends an element
+    ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, this panics if there already are four elements, see [`Heap::try_push`].
+    pub fn push(&mut self, item: T) {
+        if self.try_push(item).is_err() {
+            panic!("cannot push more than {SPILL_CAPACITY} elements without the `alloc` feature")
+        }
+    }
+
+    /// Appends an element, giving it back if there is no room for it
+    ///
+    /// This only fails without the `alloc` feature, when there already are four elements.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        #[cfg(not(feature = "alloc"))]
+        if self.len() == SPILL_CAPACITY {
+            return Err(item);
+        }
+        self.buf.push(item);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.buf.pop()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `index > len`, and without the `alloc` feature if there already are four elements.
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        self.push(item);
+        self.as_mut_slice()[index..].rotate_right(1);
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        #[cfg(feature = "alloc")]
+        return self.buf.remove(index);
+        #[cfg(not(feature = "alloc"))]
+        {
+            self.as_mut_slice()[index..].rotate_left(1);
+            self.pop().unwrap()
+        }
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+
+        self.as_mut_slice().swap(index, len - 1);
+        self.pop().unwrap()
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.buf.truncate(len)
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| keep(item))
+    }
+
+    #[cfg_attr(feature = "alloc", allow(unused_mut))]
+    pub fn retain_mut<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        #[cfg(feature = "alloc")]
+        return self.buf.retain_mut(keep);
+        #[cfg(not(feature = "alloc"))]
+        {
+            let slice = self.as_mut_slice();
+            let mut kept = 0;
+            for index in 0..slice.len() {
+                if keep(&mut slice[index]) {
+                    slice.swap(kept, index);
+                    kept += 1;
+                }
+            }
+            self.truncate(kept)
+        }
+    }
+
+    #[cfg_attr(feature = "alloc", allow(unused_mut))]
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        #[cfg(feature = "alloc")]
+        return self.buf.dedup_by(same_bucket);
+        #[cfg(not(feature = "alloc"))]
+        {
+            let slice = self.as_mut_slice();
+            if slice.is_empty() {
+                return;
+            }
+
+            let mut kept = 1;
+            for index in 1..slice.len() {
+                let (head, tail) = slice.split_at_mut(index);
+                if !same_bucket(&mut tail[0], &mut head[kept - 1]) {
+                    slice.swap(kept, index);
+                    kept += 1;
+                }
+            }
+            self.truncate(kept)
+        }
+    }
+
+    /// Removes the elements in `range`, returning them as an iterator
+    ///
+    /// Without the `alloc` feature the elements are removed eagerly, before the iterator is consumed.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        #[cfg(feature = "alloc")]
+        return Drain::many(self.buf.drain(range));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let core::ops::Range { start, end } = crate::resolve_range(range, self.len());
+            self.as_mut_slice()[start..].rotate_left(end - start);
+            Drain::many(self.split_off(self.len() - (end - start)).into_iter())
+        }
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        #[cfg(feature = "alloc")]
+        return Self {
+            buf: self.buf.split_off(at),
+        };
+        #[cfg(not(feature = "alloc"))]
+        {
+            let mut other = Self::new();
+            while self.len() > at {
+                other.push(self.pop().unwrap())
+            }
+            other.as_mut_slice().reverse();
+            other
+        }
+    }
+
+    /// Moves every element of `other` into `self`
+    ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, this panics if there would be more than four elements.
+    pub fn append(&mut self, other: &mut Self) {
+        #[cfg(feature = "alloc")]
+        self.buf.append(&mut other.buf);
+        #[cfg(not(feature = "alloc"))]
+        self.extend(other.drain(..));
+    }
+}
+
+impl<T> Clone for Heap<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for Heap<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T> core::ops::Deref for Heap<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> core::ops::DerefMut for Heap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for Heap<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for Heap<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> Eq for Heap<T> where T: Eq {}
+
+impl<T> PartialOrd for Heap<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T> Ord for Heap<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T> core::hash::Hash for Heap<T>
+where
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+/// # Panics
+///
+/// Without the `alloc` feature, this panics once there would be more than four elements, see [`Heap::push`].
+impl<T> Extend<T> for Heap<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        #[cfg(feature = "alloc")]
+        self.buf.extend(iter);
+        #[cfg(not(feature = "alloc"))]
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+/// # Panics
+///
+/// Without the `alloc` feature, this panics if `iter` has more than four elements, see [`Heap::push`].
+impl<T> FromIterator<T> for Heap<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Vec<T>> for Heap<T> {
+    fn from(buf: Vec<T>) -> Self {
+        Self { buf }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Heap<T>> for Vec<T> {
+    fn from(item: Heap<T>) -> Self {
+        item.into_vec()
+    }
+}
+
+impl<T> IntoIterator for Heap<T> {
+    type Item = T;
+    type IntoIter = HeapIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HeapIntoIter {
+            inner: self.buf.into_iter(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Heap<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Heap<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(feature = "alloc")]
+type BufIntoIter<T> = alloc::vec::IntoIter<T>;
+#[cfg(not(feature = "alloc"))]
+type BufIntoIter<T> = InlineIntoIter<T, SPILL_CAPACITY>;
+
+#[derive(Clone)]
+pub struct HeapIntoIter<T> {
+    inner: BufIntoIter<T>,
+}
+
+impl<T> HeapIntoIter<T> {
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+}
+
+impl<T> core::fmt::Debug for HeapIntoIter<T>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("HeapIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T> Iterator for HeapIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.as_slice().len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, f)
+    }
+}
+
+impl<T> DoubleEndedIterator for HeapIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n)
+    }
+
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.rfold(init, f)
+    }
+}
+
+impl<T> ExactSizeIterator for HeapIntoIter<T> {}
+
+impl<T> FusedIterator for HeapIntoIter<T> {}
@@ -0,0 +1,827 @@
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Range, RangeBounds},
+    slice::SliceIndex,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{resolve_range, Iter, IterMut, OneOrMany};
+
+/// A [`OneOrMany`] that stores up to `N` elements inline before spilling onto the heap
+///
+/// It has the same methods, iterators and serde format as [`OneOrMany`], but the storage is opaque: use
+/// [`OneOrManyInline::is_inline`] to find out where the elements currently live.
+///
+/// Without the `alloc` feature it never spills, and is limited to `N` elements.
+pub struct OneOrManyInline<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+#[derive(Clone)]
+enum Repr<T, const N: usize> {
+    Inline(InlineBuf<T, N>),
+    #[cfg(feature = "alloc")]
+    Heap(Vec<T>),
+}
+
+impl<T, const N: usize> Default for OneOrManyInline<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> OneOrManyInline<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            repr: Repr::Inline(InlineBuf::new()),
+        }
+    }
+
+    /// Whether the elements are stored inline, i.e. this has not spilled onto the heap
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline(..))
+    }
+
+    /// Whether this holds exactly one element, i.e. `len() == 1`
+    pub fn is_one(&self) -> bool {
+        self.len() == 1
+    }
+
+    /// Whether this holds more than one element, i.e. `len() > 1`
+    pub fn is_many(&self) -> bool {
+        self.len() > 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(buf) => buf.len(),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.len(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        match self.repr {
+            Repr::Inline(buf) => buf.into_iter().collect(),
+            Repr::Heap(vec) => vec,
+        }
+    }
+
+    /// Returns the element if there is exactly one, otherwise gives back `self`
+    pub fn into_single(mut self) -> Result<T, Self> {
+        match self.len() {
+            1 => Ok(self.pop().unwrap()),
+            _ => Err(self),
+        }
+    }
+
+    /// Returns the element if there is at most one, otherwise gives back `self`
+    pub fn into_option(mut self) -> Result<Option<T>, Self> {
+        match self.len() {
+            0 | 1 => Ok(self.pop()),
+            _ => Err(self),
+        }
+    }
+
+    /// Moves the elements back inline if they have spilled but would fit again
+    pub fn normalize(&mut self) {
+        #[cfg(feature = "alloc")]
+        if let Repr::Heap(vec) = &mut self.repr {
+            if vec.len() <= N {
+                let mut buf = InlineBuf::new();
+                vec.drain(..).for_each(|item| buf.push(item));
+                self.repr = Repr::Inline(buf);
+            }
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline(buf) => buf.as_slice(),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.as_slice(),
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.repr {
+            Repr::Inline(buf) => buf.as_mut_slice(),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.as_mut_slice(),
+        }
+    }
+
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.as_slice().get(index)
+    }
+
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.as_mut_slice())
+    }
+
+    /// Appends an element, spilling onto the heap if there is no room left inline
+    ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, this panics if there already are `N` elements, see
+    /// [`OneOrManyInline::try_push`].
+    pub fn push(&mut self, item: T) {
+        if self.try_push(item).is_err() {
+            panic!("cannot push more than {N} elements without the `alloc` feature")
+        }
+    }
+
+    /// Appends an element, giving it back if there is no room for it
+    ///
+    /// This only fails without the `alloc` feature, when there already are `N` elements.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        match &mut self.repr {
+            Repr::Inline(buf) if buf.len() < N => buf.push(item),
+
+            #[cfg(feature = "alloc")]
+            Repr::Inline(buf) => {
+                let mut vec = Vec::with_capacity(N.max(1) * 2);
+                vec.extend(core::mem::take(buf));
+                vec.push(item);
+                self.repr = Repr::Heap(vec);
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            Repr::Inline(..) => return Err(item),
+
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.push(item),
+        }
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.repr {
+            Repr::Inline(buf) => buf.pop(),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.pop(),
+        }
+    }
+
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        self.push(item);
+        self.as_mut_slice()[index..].rotate_right(1);
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        self.as_mut_slice()[index..].rotate_left(1);
+        self.pop().unwrap()
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+
+        self.as_mut_slice().swap(index, len - 1);
+        self.pop().unwrap()
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.repr {
+            Repr::Inline(buf) => buf.truncate(len),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => vec.truncate(len),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| keep(item))
+    }
+
+    pub fn retain_mut<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let slice = self.as_mut_slice();
+        let mut kept = 0;
+        for index in 0..slice.len() {
+            if keep(&mut slice[index]) {
+                slice.swap(kept, index);
+                kept += 1;
+            }
+        }
+        self.truncate(kept)
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let slice = self.as_mut_slice();
+        if slice.is_empty() {
+            return;
+        }
+
+        let mut kept = 1;
+        for index in 1..slice.len() {
+            let (head, tail) = slice.split_at_mut(index);
+            if !same_bucket(&mut tail[0], &mut head[kept - 1]) {
+                slice.swap(kept, index);
+                kept += 1;
+            }
+        }
+        self.truncate(kept)
+    }
+
+    /// Removes the elements in `range`, returning them as an iterator
+    ///
+    /// Unlike [`OneOrMany::drain`] the elements are removed eagerly, before the iterator is consumed.
+    pub fn drain<R>(&mut self, range: R) -> OneOrManyInlineIntoIter<T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = resolve_range(range, self.len());
+        self.as_mut_slice()[start..].rotate_left(end - start);
+        self.split_off(self.len() - (end - start)).into_iter()
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        #[cfg(feature = "alloc")]
+        if let Repr::Heap(vec) = &mut self.repr {
+            return vec.drain(at..).collect();
+        }
+
+        let mut other = Self::new();
+        while self.len() > at {
+            other.push(self.pop().unwrap())
+        }
+        other.as_mut_slice().reverse();
+        other
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend(other.drain(..))
+    }
+}
+
+impl<T, const N: usize> Clone for OneOrManyInline<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repr: self.repr.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for OneOrManyInline<T, N>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for OneOrManyInline<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for OneOrManyInline<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I, const N: usize> core::ops::Index<I> for OneOrManyInline<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I, const N: usize> core::ops::IndexMut<I> for OneOrManyInline<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for OneOrManyInline<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for OneOrManyInline<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> core::borrow::Borrow<[T]> for OneOrManyInline<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> core::borrow::BorrowMut<[T]> for OneOrManyInline<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<OneOrManyInline<U, M>>
+    for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrManyInline<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<OneOrMany<U>> for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrMany<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<OneOrManyInline<U, N>> for OneOrMany<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrManyInline<U, N>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<&[U]> for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U, const N: usize> PartialEq<Vec<U>> for OneOrManyInline<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> Eq for OneOrManyInline<T, N> where T: Eq {}
+
+impl<T, const N: usize> PartialOrd for OneOrManyInline<T, N>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T, const N: usize> Ord for OneOrManyInline<T, N>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, const N: usize> core::hash::Hash for OneOrManyInline<T, N>
+where
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> Extend<T> for OneOrManyInline<T, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| self.push(item))
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for OneOrManyInline<T, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<T, const N: usize> From<T> for OneOrManyInline<T, N> {
+    fn from(item: T) -> Self {
+        let mut this = Self::new();
+        this.push(item);
+        this
+    }
+}
+
+impl<T, const N: usize> From<Option<T>> for OneOrManyInline<T, N> {
+    fn from(item: Option<T>) -> Self {
+        item.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<Vec<T>> for OneOrManyInline<T, N> {
+    fn from(item: Vec<T>) -> Self {
+        let mut this = Self {
+            repr: Repr::Heap(item),
+        };
+        this.normalize();
+        this
+    }
+}
+
+impl<T, const N: usize> From<OneOrMany<T>> for OneOrManyInline<T, N> {
+    fn from(item: OneOrMany<T>) -> Self {
+        match item {
+            OneOrMany::Single(one) => Self::from(one),
+            #[cfg(feature = "alloc")]
+            OneOrMany::Many(many) => Self::from(many.into_vec()),
+            #[cfg(not(feature = "alloc"))]
+            OneOrMany::Many(many) => many.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<OneOrManyInline<T, N>> for OneOrMany<T> {
+    fn from(item: OneOrManyInline<T, N>) -> Self {
+        match item.into_option() {
+            Ok(one) => Self::Single(one),
+            Err(many) => Self::Many(many.into_vec().into()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<OneOrManyInline<T, N>> for Vec<T> {
+    fn from(item: OneOrManyInline<T, N>) -> Self {
+        item.into_vec()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for OneOrManyInline<T, N> {
+    type Item = T;
+    type IntoIter = OneOrManyInlineIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.repr {
+            Repr::Inline(buf) => IntoIterInner::Inline(buf.into_iter()),
+            #[cfg(feature = "alloc")]
+            Repr::Heap(vec) => IntoIterInner::Heap(vec.into_iter()),
+        };
+        OneOrManyInlineIntoIter { inner }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a OneOrManyInline<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut OneOrManyInline<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[derive(Clone)]
+pub struct OneOrManyInlineIntoIter<T, const N: usize> {
+    inner: IntoIterInner<T, N>,
+}
+
+#[derive(Clone)]
+enum IntoIterInner<T, const N: usize> {
+    Inline(InlineIntoIter<T, N>),
+    #[cfg(feature = "alloc")]
+    Heap(alloc::vec::IntoIter<T>),
+}
+
+impl<T, const N: usize> OneOrManyInlineIntoIter<T, N> {
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            IntoIterInner::Inline(n) => n.as_slice(),
+            #[cfg(feature = "alloc")]
+            IntoIterInner::Heap(n) => n.as_slice(),
+        }
+    }
+}
+
+impl<T, const N: usize> core::fmt::Debug for OneOrManyInlineIntoIter<T, N>
+where
+    T: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OneOrManyInlineIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Iterator for OneOrManyInlineIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IntoIterInner::Inline(n) => n.next(),
+            #[cfg(feature = "alloc")]
+            IntoIterInner::Heap(n) => n.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.as_slice().len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for OneOrManyInlineIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IntoIterInner::Inline(n) => n.next_back(),
+            #[cfg(feature = "alloc")]
+            IntoIterInner::Heap(n) => n.next_back(),
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for OneOrManyInlineIntoIter<T, N> {}
+
+impl<T, const N: usize> core::iter::FusedIterator for OneOrManyInlineIntoIter<T, N> {}
+
+// the first `len` elements of `buf` are initialized
+pub(crate) struct InlineBuf<T, const N: usize> {
+    len: usize,
+    buf: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> InlineBuf<T, N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            len: 0,
+            buf: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len) }
+    }
+
+    // callers have to make sure there is room left
+    pub(crate) fn push(&mut self, item: T) {
+        self.buf[self.len].write(item);
+        self.len += 1;
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // SAFETY: the element at `len` was initialized, and is no longer tracked by `len`
+        Some(unsafe { self.buf[self.len].assume_init_read() })
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            drop(self.pop())
+        }
+    }
+}
+
+impl<T, const N: usize> Default for InlineBuf<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for InlineBuf<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut this = Self::new();
+        self.as_slice()
+            .iter()
+            .for_each(|item| this.push(item.clone()));
+        this
+    }
+}
+
+impl<T, const N: usize> Drop for InlineBuf<T, N> {
+    fn drop(&mut self) {
+        self.truncate(0)
+    }
+}
+
+impl<T, const N: usize> IntoIterator for InlineBuf<T, N> {
+    type Item = T;
+    type IntoIter = InlineIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let this = ManuallyDrop::new(self);
+        InlineIntoIter {
+            // SAFETY: `this` is never dropped, so ownership of the elements moves into the iterator
+            buf: unsafe { core::ptr::read(&this.buf) },
+            range: 0..this.len,
+        }
+    }
+}
+
+// the elements in `range` are initialized
+pub(crate) struct InlineIntoIter<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    range: Range<usize>,
+}
+
+impl<T, const N: usize> InlineIntoIter<T, N> {
+    pub(crate) fn as_slice(&self) -> &[T] {
+        let slice = &self.buf[self.range.clone()];
+        // SAFETY: the elements in `range` are initialized
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    // only a `Heap` without `alloc` hands out mutable access to what is left
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        let slice = &mut self.buf[self.range.clone()];
+        // SAFETY: the elements in `range` are initialized
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+    }
+}
+
+impl<T, const N: usize> Iterator for InlineIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        // SAFETY: the element was in `range`, and is no longer tracked by it
+        Some(unsafe { self.buf[index].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for InlineIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.range.next_back()?;
+        // SAFETY: the element was in `range`, and is no longer tracked by it
+        Some(unsafe { self.buf[index].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> Clone for InlineIntoIter<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut buf = InlineBuf::new();
+        self.as_slice()
+            .iter()
+            .for_each(|item| buf.push(item.clone()));
+        buf.into_iter()
+    }
+}
+
+impl<T, const N: usize> Drop for InlineIntoIter<T, N> {
+    fn drop(&mut self) {
+        self.by_ref().for_each(drop)
+    }
+}
@@ -0,0 +1,293 @@
+use core::{iter::FusedIterator, marker::PhantomData};
+
+use crate::HeapIntoIter;
+
+#[derive(Clone, Debug)]
+pub enum OneOrManyIntoIter<T> {
+    Single(Option<T>),
+    Many(HeapIntoIter<T>),
+}
+
+impl<T> OneOrManyIntoIter<T> {
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            Self::Single(n) => n.as_slice(),
+            Self::Many(n) => n.as_slice(),
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            Self::Single(n) => n.as_mut_slice(),
+            Self::Many(n) => n.as_mut_slice(),
+        }
+    }
+}
+
+impl<T> Iterator for OneOrManyIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Single(n) => n.take(),
+            Self::Many(n) => n.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Single(n) => {
+                let len = n.is_some() as usize;
+                (len, Some(len))
+            }
+            Self::Many(n) => n.size_hint(),
+        }
+    }
+
+    fn nth(&mut self, index: usize) -> Option<Self::Item> {
+        match self {
+            Self::Single(n) => n.take().filter(|_| index == 0),
+            Self::Many(n) => n.nth(index),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            Self::Single(n) => n.into_iter().fold(init, f),
+            Self::Many(n) => n.fold(init, f),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for OneOrManyIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Single(n) => n.take(),
+            Self::Many(n) => n.next_back(),
+        }
+    }
+
+    fn nth_back(&mut self, index: usize) -> Option<Self::Item> {
+        match self {
+            Self::Single(n) => n.take().filter(|_| index == 0),
+            Self::Many(n) => n.nth_back(index),
+        }
+    }
+
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            Self::Single(n) => n.into_iter().rfold(init, f),
+            Self::Many(n) => n.rfold(init, f),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for OneOrManyIntoIter<T> {}
+
+impl<T> FusedIterator for OneOrManyIntoIter<T> {}
+
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T> {
+    inner: core::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        Self {
+            inner: slice.iter(),
+        }
+    }
+
+    pub fn as_slice(&self) -> &'a [T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: core::slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            inner: slice.iter_mut(),
+        }
+    }
+
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.inner.into_slice()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+#[derive(Debug)]
+pub struct Drain<'a, T> {
+    inner: DrainInner<'a, T>,
+}
+
+#[derive(Debug)]
+enum DrainInner<'a, T> {
+    // the marker keeps the borrow of the source alive when there is no `Many`
+    Single(Option<T>, PhantomData<&'a mut [T]>),
+    #[cfg(feature = "alloc")]
+    Many(alloc::vec::Drain<'a, T>),
+    // without `alloc` a `Heap` is drained eagerly
+    #[cfg(not(feature = "alloc"))]
+    Many(HeapIntoIter<T>),
+}
+
+impl<'a, T> Drain<'a, T> {
+    pub(crate) const fn single(item: Option<T>) -> Self {
+        Self {
+            inner: DrainInner::Single(item, PhantomData),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn many(drain: alloc::vec::Drain<'a, T>) -> Self {
+        Self {
+            inner: DrainInner::Many(drain),
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn many(drain: HeapIntoIter<T>) -> Self {
+        Self {
+            inner: DrainInner::Many(drain),
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            DrainInner::Single(n, ..) => n.as_slice(),
+            DrainInner::Many(n) => n.as_slice(),
+        }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            DrainInner::Single(n, ..) => n.take(),
+            DrainInner::Many(n) => n.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.as_slice().len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            DrainInner::Single(n, ..) => n.take(),
+            DrainInner::Many(n) => n.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
@@ -1,8 +1,90 @@
+//! A type that stores 0, 1 or many instances of a type
+//!
+//! # Features
+//!
+//! - `std` (default): enables `alloc`, and `std` support in optional dependencies
+//! - `alloc`: stores the elements of `Many` in a `Vec` on the heap, and enables everything built on `Vec`
+//! - `serde`: (de)serialization as a bare value or a sequence, see the `serde` module
+//! - `schemars`: enables `serde`, and JSON Schemas for its format through `schemars::JsonSchema`
+//! - `utoipa`: OpenAPI schemas for the `serde` format, through `utoipa::ToSchema`
+//! - `clap`: parsing (delimited) command line arguments, see the `clap` module
+//!
+//! Without `alloc` the crate only needs `core`, the elements of `Many` are then stored inline and a [`OneOrMany`] is
+//! limited to four elements.
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod iter;
+pub use iter::{Drain, Iter, IterMut, OneOrManyIntoIter};
+
+mod heap;
+use heap::SPILL_CAPACITY;
+pub use heap::{Heap, HeapIntoIter};
+
+mod normalize;
+pub use normalize::AutoNormalize;
+
+mod inline;
+pub use inline::{OneOrManyInline, OneOrManyInlineIntoIter};
+
+mod one_or_more;
+pub use one_or_more::OneOrMore;
+
+mod bounded;
+pub use bounded::{AtMost, Between, CardinalityError, Exactly};
+
+mod view;
+pub use view::{OwnedView, View, ViewMut};
+
+mod display;
+pub use display::DisplayWith;
+
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "alloc")]
+pub use parse::ParseError;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::{TryReserveError, VecDeque},
+    rc::Rc,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::convert::Infallible;
+
+use core::{
+    ops::{Bound, Range, RangeBounds},
+    slice::SliceIndex,
+};
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+/// Zero or one element inline, or any number of elements on the heap
+///
+/// Without the `alloc` feature a [`Heap`] stores its elements inline instead, and holds at most four of them. It has
+/// the same methods either way, so matching on a `OneOrMany` compiles the same way with or without `alloc`.
 #[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OneOrMany<T> {
     Single(Option<T>),
-    Many(Vec<T>),
+    Many(Heap<T>),
 }
 
 impl<T> Default for OneOrMany<T> {
@@ -16,27 +98,277 @@ impl<T> OneOrMany<T> {
         Self::Single(None)
     }
 
+    /// Creates an empty `Many` with room for `capacity` elements, or a `Single` if that's at most one
+    #[cfg(feature = "alloc")]
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= 1 {
+            return Self::new();
+        }
+        Self::Many(Heap::with_capacity(capacity))
+    }
+
+    /// How many elements this can hold without allocating, a `Single` can always hold one
+    pub fn capacity(&self) -> usize {
+        match self {
+            Self::Single(..) => 1,
+            Self::Many(many) => many.capacity(),
+        }
+    }
+
+    /// Reserves room for at least `additional` more elements, moving a `Single` into a `Many` if it needs to
+    #[cfg(feature = "alloc")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.reserve_with(additional, |many, additional| {
+            many.reserve(additional);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Reserves room for exactly `additional` more elements, moving a `Single` into a `Many` if it needs to
+    #[cfg(feature = "alloc")]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.reserve_with(additional, |many, additional| {
+            many.reserve_exact(additional);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Like [`OneOrMany::reserve`], but returns an error instead of panicking or aborting
+    #[cfg(feature = "alloc")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.reserve_with(additional, Heap::try_reserve)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn reserve_with<E>(
+        &mut self,
+        additional: usize,
+        reserve: impl FnOnce(&mut Heap<T>, usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        match self {
+            Self::Single(one) => {
+                let capacity = usize::from(one.is_some()).saturating_add(additional);
+                if capacity > 1 {
+                    let mut many = Heap::new();
+                    reserve(&mut many, capacity)?;
+                    many.extend(one.take());
+                    *self = Self::Many(many);
+                }
+                Ok(())
+            }
+            Self::Many(many) => reserve(many, additional),
+        }
+    }
+
+    /// Normalizes this, then shrinks the capacity of a `Many` as much as possible
+    pub fn shrink_to_fit(&mut self) {
+        self.normalize();
+        #[cfg(feature = "alloc")]
+        if let Self::Many(many) = self {
+            many.shrink_to_fit()
+        }
+    }
+
+    /// Whether this holds exactly one element, i.e. `len() == 1`
+    ///
+    /// This is true for a `Many` with a single element as well.
     pub const fn is_one(&self) -> bool {
-        matches!(self, Self::Single(Some(..)))
+        match self {
+            Self::Single(one) => one.is_some(),
+            Self::Many(many) => many.len() == 1,
+        }
     }
 
+    /// Whether this holds more than one element, i.e. `len() > 1`
+    ///
+    /// This is false for a `Many` with zero or one elements.
     pub const fn is_many(&self) -> bool {
-        matches!(self, Self::Many(..))
+        match self {
+            Self::Single(..) => false,
+            Self::Many(many) => many.len() > 1,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.len() != 0
+        self.len() == 0
     }
 
     pub fn len(&self) -> usize {
         match self {
             Self::Single(Some(..)) => 1,
-            Self::Many(vec) => vec.len(),
-            _ => 0,
+            Self::Single(None) => 0,
+            Self::Many(many) => many.len(),
         }
     }
 
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            Self::Single(one) => one.into_iter().collect(),
+            Self::Many(many) => many.into_vec(),
+        }
+    }
+
+    /// Returns the element if there is exactly one, otherwise gives back `self`
+    pub fn into_single(self) -> Result<T, Self> {
+        match self {
+            Self::Single(Some(one)) => Ok(one),
+            Self::Many(mut many) if many.len() == 1 => Ok(many.pop().unwrap()),
+            this => Err(this),
+        }
+    }
+
+    /// Returns the element if there is at most one, otherwise gives back `self`
+    pub fn into_option(self) -> Result<Option<T>, Self> {
+        match self {
+            Self::Single(one) => Ok(one),
+            Self::Many(mut many) if many.len() <= 1 => Ok(many.pop()),
+            this => Err(this),
+        }
+    }
+
+    /// Collapses a `Many` with zero or one elements back into a `Single`
+    pub fn normalize(&mut self) {
+        if let Self::Many(many) = self {
+            if many.len() <= 1 {
+                *self = Self::Single(many.pop())
+            }
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            Self::Single(one) => one.as_slice(),
+            Self::Many(many) => many.as_slice(),
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            Self::Single(one) => one.as_mut_slice(),
+            Self::Many(many) => many.as_mut_slice(),
+        }
+    }
+
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.as_slice().get(index)
+    }
+
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Returns the first element, pushing `item` first if there are none
+    pub fn first_or_insert(&mut self, item: T) -> &mut T {
+        self.get_or_insert_with(|| item)
+    }
+
+    /// Returns the first element, pushing the result of `f` first if there are none
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if self.is_empty() {
+            self.push(f());
+        }
+        self.first_mut().unwrap()
+    }
+
+    /// Replaces the contents with just `item`, returning the old contents
+    pub fn replace(&mut self, item: T) -> Self {
+        core::mem::replace(self, Self::Single(Some(item)))
+    }
+
+    /// Takes the contents, leaving `Single(None)` in their place
+    ///
+    /// This is the same as [`core::mem::take`], it doesn't allocate.
+    pub fn take(&mut self) -> Self {
+        core::mem::take(self)
+    }
+
+    /// Replaces the contents with just `item`
+    pub fn set(&mut self, item: T) {
+        *self = Self::Single(Some(item))
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.as_mut_slice())
+    }
+
+    /// Appends an element
+    ///
+    /// # Panics
+    ///
+    /// Without the `alloc` feature, this panics if there already are four elements, see [`OneOrMany::try_push`].
     pub fn push(&mut self, item: T) {
+        if self.try_push(item).is_err() {
+            panic!("cannot push more than {SPILL_CAPACITY} elements without the `alloc` feature")
+        }
+    }
+
+    /// Appends an element, giving it back if there is no room for it
+    ///
+    /// This only fails without the `alloc` feature, when there already are four elements.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        match self {
+            Self::Single(vacant @ None) => {
+                vacant.get_or_insert(item);
+            }
+
+            Self::Single(occupied) => {
+                let mut many = Heap::spill();
+                many.extend(occupied.take());
+                many.push(item);
+                *self = Self::Many(many);
+            }
+
+            Self::Many(list) => return list.try_push(item),
+        }
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        match self {
+            Self::Single(one) => one.take(),
+            Self::Many(many) => many.pop(),
+        }
+    }
+
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
         match self {
             Self::Single(vacant @ None) => {
                 vacant.get_or_insert(item);
@@ -44,34 +376,535 @@ impl<T> OneOrMany<T> {
 
             Self::Single(occupied) => {
                 let this = occupied.take().unwrap();
-                *self = Self::Many(vec![this, item]);
+                let mut list = Heap::spill();
+                if index == 0 {
+                    list.extend([item, this]);
+                } else {
+                    list.extend([this, item]);
+                }
+                *self = Self::Many(list);
             }
 
             Self::Many(list) => {
-                list.push(item);
+                list.insert(index, item);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        match self {
+            Self::Single(one) => one.take().unwrap(),
+            Self::Many(many) => many.remove(index),
+        }
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {index}) should be < len (is {len})"
+        );
+
+        match self {
+            Self::Single(one) => one.take().unwrap(),
+            Self::Many(many) => many.swap_remove(index),
+        }
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        match self {
+            Self::Single(one) if len == 0 => *one = None,
+            Self::Single(..) => {}
+            Self::Many(many) => many.truncate(len),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| keep(item))
+    }
+
+    pub fn retain_mut<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        match self {
+            Self::Single(one) => {
+                if one.as_mut().is_some_and(|item| !keep(item)) {
+                    *one = None
+                }
             }
+            Self::Many(many) => many.retain_mut(keep),
         }
     }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        match self {
+            Self::Single(..) => {}
+            Self::Many(many) => many.dedup_by(same_bucket),
+        }
+    }
+
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len());
+        match self {
+            Self::Single(..) if range.is_empty() => Drain::single(None),
+            Self::Single(one) => Drain::single(one.take()),
+            Self::Many(many) => many.drain(range),
+        }
+    }
+
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        match self {
+            Self::Single(one) if at == 0 => Self::Single(one.take()),
+            Self::Single(..) => Self::Single(None),
+            Self::Many(many) => Self::Many(many.split_off(at)),
+        }
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        match (self, other) {
+            (Self::Many(left), Self::Many(right)) => left.append(right),
+            (this, other) => this.extend(other.drain(..)),
+        }
+    }
+
+    /// Maps every element, keeping the variant
+    pub fn map<U, F>(self, f: F) -> OneOrMany<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            Self::Single(one) => OneOrMany::Single(one.map(f)),
+            Self::Many(many) => OneOrMany::Many(many.into_iter().map(f).collect()),
+        }
+    }
+
+    /// Maps every element, keeping the variant, stopping at the first error
+    pub fn try_map<U, E, F>(self, f: F) -> Result<OneOrMany<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        match self {
+            Self::Single(one) => one.map(f).transpose().map(OneOrMany::Single),
+            Self::Many(many) => many
+                .into_iter()
+                .map(f)
+                .collect::<Result<_, _>>()
+                .map(OneOrMany::Many),
+        }
+    }
+
+    /// Maps a reference to every element, keeping the variant
+    pub fn map_ref<U, F>(&self, f: F) -> OneOrMany<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        match self {
+            Self::Single(one) => OneOrMany::Single(one.as_ref().map(f)),
+            Self::Many(many) => OneOrMany::Many(many.iter().map(f).collect()),
+        }
+    }
+
+    /// Maps every element, dropping those that map to `None`, keeping the variant
+    ///
+    /// A `Many` stays a `Many` even if fewer than two elements are left.
+    pub fn filter_map<U, F>(self, f: F) -> OneOrMany<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        match self {
+            Self::Single(one) => OneOrMany::Single(one.and_then(f)),
+            Self::Many(many) => OneOrMany::Many(many.into_iter().filter_map(f).collect()),
+        }
+    }
+
+    /// Maps every element to any number of elements
+    ///
+    /// A `Many` stays a `Many`, a `Single` becomes whatever its mapped elements collect into.
+    pub fn flat_map<U, I, F>(self, f: F) -> OneOrMany<U>
+    where
+        I: IntoIterator<Item = U>,
+        F: FnMut(T) -> I,
+    {
+        match self {
+            Self::Single(one) => one.into_iter().flat_map(f).collect(),
+            Self::Many(many) => OneOrMany::Many(many.into_iter().flat_map(f).collect()),
+        }
+    }
+
+    /// Borrows every element, keeping the variant
+    pub fn each_ref(&self) -> OneOrMany<&T> {
+        match self {
+            Self::Single(one) => OneOrMany::Single(one.as_ref()),
+            Self::Many(many) => OneOrMany::Many(many.iter().collect()),
+        }
+    }
+
+    /// Mutably borrows every element, keeping the variant
+    pub fn each_mut(&mut self) -> OneOrMany<&mut T> {
+        match self {
+            Self::Single(one) => OneOrMany::Single(one.as_mut()),
+            Self::Many(many) => OneOrMany::Many(many.iter_mut().collect()),
+        }
+    }
+
+    /// Dereferences every element, keeping the variant
+    pub fn as_deref(&self) -> OneOrMany<&T::Target>
+    where
+        T: core::ops::Deref,
+    {
+        self.each_ref().map(|item| &**item)
+    }
+}
+
+impl<T> OneOrMany<&T> {
+    /// Clones every element, keeping the variant
+    pub fn cloned(self) -> OneOrMany<T>
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
+
+    /// Copies every element, keeping the variant
+    pub fn copied(self) -> OneOrMany<T>
+    where
+        T: Copy,
+    {
+        self.map(|item| *item)
+    }
+}
+
+impl<T> OneOrMany<&mut T> {
+    /// Clones every element, keeping the variant
+    pub fn cloned(self) -> OneOrMany<T>
+    where
+        T: Clone,
+    {
+        self.map(|item| item.clone())
+    }
+
+    /// Copies every element, keeping the variant
+    pub fn copied(self) -> OneOrMany<T>
+    where
+        T: Copy,
+    {
+        self.map(|item| *item)
+    }
+}
+
+impl<T> OneOrMany<Option<T>> {
+    /// Returns `None` if any element is `None`, keeping the variant otherwise
+    pub fn transpose(self) -> Option<OneOrMany<T>> {
+        self.try_map(|item| item.ok_or(())).ok()
+    }
+}
+
+impl<T, E> OneOrMany<Result<T, E>> {
+    /// Returns the first error if there is one, keeping the variant otherwise
+    pub fn transpose(self) -> Result<OneOrMany<T>, E> {
+        self.try_map(|item| item)
+    }
+
+    /// Like [`OneOrMany::transpose`], but returns every error rather than only the first
+    ///
+    /// The errors keep the variant of `self`.
+    pub fn transpose_all(self) -> Result<OneOrMany<T>, OneOrMany<E>> {
+        match self {
+            Self::Single(None) => Ok(OneOrMany::Single(None)),
+            Self::Single(Some(item)) => item
+                .map(|ok| OneOrMany::Single(Some(ok)))
+                .map_err(|err| OneOrMany::Single(Some(err))),
+            Self::Many(many) => {
+                let (mut oks, mut errs) = (Heap::new(), Heap::new());
+                for item in many {
+                    match item {
+                        Ok(ok) => oks.push(ok),
+                        Err(err) => errs.push(err),
+                    }
+                }
+                if errs.is_empty() {
+                    return Ok(OneOrMany::Many(oks));
+                }
+                Err(OneOrMany::Many(errs))
+            }
+        }
+    }
+}
+
+// resolves a range against a length, panicking the same way slice indexing does
+pub(crate) fn resolve_range<R>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= len,
+        "range end index {end} out of range for slice of length {len}"
+    );
+
+    start..end
+}
+
+impl<T> core::ops::Deref for OneOrMany<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> core::ops::DerefMut for OneOrMany<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I> core::ops::Index<I> for OneOrMany<T>
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, I> core::ops::IndexMut<I> for OneOrMany<T>
+where
+    I: SliceIndex<[T]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T> AsRef<[T]> for OneOrMany<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for OneOrMany<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> core::borrow::Borrow<[T]> for OneOrMany<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> core::borrow::BorrowMut<[T]> for OneOrMany<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+// comparisons are in terms of the elements, not the representation: `Single(Some(x)) == Many(vec![x])`
+impl<T, U> PartialEq<OneOrMany<U>> for OneOrMany<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrMany<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T> Eq for OneOrMany<T> where T: Eq {}
+
+impl<T> PartialOrd for OneOrMany<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T> Ord for OneOrMany<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+// this has to hash the same as `[T]` because of the `Borrow<[T]>` impl
+impl<T> core::hash::Hash for OneOrMany<T>
+where
+    T: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+macro_rules! impl_slice_eq {
+    ($([$($generics:tt)*] $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<T, U, $($generics)*> PartialEq<$rhs> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &$rhs) -> bool {
+                    slice_of(self) == slice_of(other)
+                }
+            }
+        )*
+    };
+}
+
+fn slice_of<S, T>(slice: &S) -> &[T]
+where
+    S: AsRef<[T]> + ?Sized,
+{
+    slice.as_ref()
+}
+
+impl_slice_eq! {
+    [] OneOrMany<T>, [U];
+    [] OneOrMany<T>, &[U];
+    [] OneOrMany<T>, &mut [U];
+    [const N: usize] OneOrMany<T>, [U; N];
+    [const N: usize] OneOrMany<T>, &[U; N];
+    [] [T], OneOrMany<U>;
+    [] &[T], OneOrMany<U>;
+    [] &mut [T], OneOrMany<U>;
+    [const N: usize] [T; N], OneOrMany<U>;
+    [const N: usize] &[T; N], OneOrMany<U>;
+    [] Heap<T>, Heap<U>;
+    [] Heap<T>, [U];
+    [] Heap<T>, &[U];
+    [] Heap<T>, &mut [U];
+    [const N: usize] Heap<T>, [U; N];
+    [const N: usize] Heap<T>, &[U; N];
+    [] [T], Heap<U>;
+    [] &[T], Heap<U>;
+    [] &mut [T], Heap<U>;
+    [const N: usize] [T; N], Heap<U>;
+    [const N: usize] &[T; N], Heap<U>;
+}
+
+#[cfg(feature = "alloc")]
+impl_slice_eq! {
+    [] OneOrMany<T>, Vec<U>;
+    [] Vec<T>, OneOrMany<U>;
+    [] Heap<T>, Vec<U>;
+    [] Vec<T>, Heap<U>;
+}
+
+impl<T, U> PartialEq<Option<U>> for OneOrMany<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Option<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
 }
 
+impl<T, U> PartialEq<OneOrMany<U>> for Option<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrMany<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+/// # Panics
+///
+/// Without the `alloc` feature, this panics once there would be more than four elements, see [`OneOrMany::push`].
 impl<T> Extend<T> for OneOrMany<T> {
     fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = T>,
     {
-        iter.into_iter().for_each(|item| self.push(item))
+        let iter = iter.into_iter();
+        #[cfg(feature = "alloc")]
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|item| self.push(item))
     }
 }
 
+/// Through the standard library this also collects an iterator of `Result<T, E>` into a `Result<OneOrMany<T>, E>`
+/// (and of `Option<T>` into an `Option<OneOrMany<T>>`), stopping at the first error.
+///
+/// # Panics
+///
+/// Without the `alloc` feature, this panics if `iter` has more than four elements, see [`OneOrMany::push`].
 impl<T> FromIterator<T> for OneOrMany<T> {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
     {
-        iter.into_iter().fold(Self::new(), |mut this, item| {
-            this.push(item);
-            this
-        })
+        let mut this = Self::new();
+        this.extend(iter);
+        this
     }
 }
 
@@ -87,9 +920,80 @@ impl<T> From<T> for OneOrMany<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<Vec<T>> for OneOrMany<T> {
     fn from(item: Vec<T>) -> Self {
-        Self::Many(item)
+        Self::Many(item.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Box<[T]>> for OneOrMany<T> {
+    fn from(item: Box<[T]>) -> Self {
+        Self::Many(item.into_vec().into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<VecDeque<T>> for OneOrMany<T> {
+    fn from(item: VecDeque<T>) -> Self {
+        Self::Many(Vec::from(item).into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(item: OneOrMany<T>) -> Self {
+        item.into_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<OneOrMany<T>> for Box<[T]> {
+    fn from(item: OneOrMany<T>) -> Self {
+        item.into_vec().into_boxed_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<OneOrMany<T>> for VecDeque<T> {
+    fn from(item: OneOrMany<T>) -> Self {
+        item.into_vec().into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<OneOrMany<T>> for Rc<[T]> {
+    fn from(item: OneOrMany<T>) -> Self {
+        item.into_vec().into()
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> From<OneOrMany<T>> for Arc<[T]> {
+    fn from(item: OneOrMany<T>) -> Self {
+        item.into_vec().into()
+    }
+}
+
+impl<T> TryFrom<OneOrMany<T>> for Option<T> {
+    type Error = OneOrMany<T>;
+
+    fn try_from(item: OneOrMany<T>) -> Result<Self, Self::Error> {
+        item.into_option()
+    }
+}
+
+impl<T, const N: usize> TryFrom<OneOrMany<T>> for [T; N] {
+    type Error = OneOrMany<T>;
+
+    fn try_from(item: OneOrMany<T>) -> Result<Self, Self::Error> {
+        if item.len() != N {
+            return Err(item);
+        }
+
+        let mut iter = item.into_iter();
+        Ok(core::array::from_fn(|_| iter.next().unwrap()))
     }
 }
 
@@ -105,18 +1009,20 @@ impl<T> IntoIterator for OneOrMany<T> {
     }
 }
 
-pub enum OneOrManyIntoIter<T> {
-    Single(Option<T>),
-    Many(std::vec::IntoIter<T>),
+impl<'a, T> IntoIterator for &'a OneOrMany<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl<T> Iterator for OneOrManyIntoIter<T> {
-    type Item = T;
+impl<'a, T> IntoIterator for &'a mut OneOrMany<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self {
-            Self::Single(n) => n.take(),
-            Self::Many(n) => n.next(),
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
@@ -0,0 +1,193 @@
+use crate::{Iter, IterMut, OneOrMany, OneOrManyIntoIter};
+
+/// A [`OneOrMany`] that is normalized after every mutation
+///
+/// A `Many` with zero or one elements is collapsed back into a `Single`, so matching on the inner value always agrees
+/// with [`OneOrMany::is_one`] and [`OneOrMany::is_many`].
+///
+/// This only hands out shared access to the inner [`OneOrMany`], use [`AutoNormalize::into_inner`] to get it back.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AutoNormalize<T> {
+    inner: OneOrMany<T>,
+}
+
+impl<T> Default for AutoNormalize<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AutoNormalize<T> {
+    pub const fn new() -> Self {
+        Self {
+            inner: OneOrMany::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> OneOrMany<T> {
+        self.inner
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.inner.push(item)
+    }
+
+    pub fn insert(&mut self, index: usize, item: T) {
+        self.inner.insert(index, item)
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.normalized(|inner| inner.append(&mut other.inner));
+        other.inner.normalize();
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.normalized(|inner| inner.pop())
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        self.normalized(|inner| inner.remove(index))
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        self.normalized(|inner| inner.swap_remove(index))
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.normalized(|inner| inner.truncate(len))
+    }
+
+    pub fn clear(&mut self) {
+        self.normalized(|inner| inner.clear())
+    }
+
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.normalized(|inner| inner.retain(keep))
+    }
+
+    pub fn retain_mut<F>(&mut self, keep: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.normalized(|inner| inner.retain_mut(keep))
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.normalized(|inner| inner.dedup())
+    }
+
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.normalized(|inner| inner.dedup_by_key(key))
+    }
+
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.normalized(|inner| inner.dedup_by(same_bucket))
+    }
+
+    /// Splits off the elements from `at`, both halves are normalized
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let inner = self.normalized(|inner| inner.split_off(at));
+        Self::from(inner)
+    }
+
+    fn normalized<R>(&mut self, f: impl FnOnce(&mut OneOrMany<T>) -> R) -> R {
+        let out = f(&mut self.inner);
+        self.inner.normalize();
+        out
+    }
+}
+
+impl<T> core::ops::Deref for AutoNormalize<T> {
+    type Target = OneOrMany<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> AsRef<OneOrMany<T>> for AutoNormalize<T> {
+    fn as_ref(&self) -> &OneOrMany<T> {
+        &self.inner
+    }
+}
+
+impl<T> From<OneOrMany<T>> for AutoNormalize<T> {
+    fn from(mut inner: OneOrMany<T>) -> Self {
+        inner.normalize();
+        Self { inner }
+    }
+}
+
+impl<T> From<AutoNormalize<T>> for OneOrMany<T> {
+    fn from(value: AutoNormalize<T>) -> Self {
+        value.inner
+    }
+}
+
+impl<T> Extend<T> for AutoNormalize<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T> FromIterator<T> for AutoNormalize<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            inner: OneOrMany::from_iter(iter),
+        }
+    }
+}
+
+impl<T> IntoIterator for AutoNormalize<T> {
+    type Item = T;
+    type IntoIter = OneOrManyIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AutoNormalize<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut AutoNormalize<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
@@ -0,0 +1,267 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Iter, IterMut, OneOrMany, OneOrManyIntoIter};
+
+/// A [`OneOrMany`] that always holds at least one element
+///
+/// Because it can never be empty, [`OneOrMore::first`], [`OneOrMore::last`] and [`OneOrMore::reduce`] don't return
+/// an `Option`. A single element is stored inline, like [`OneOrMany::Single`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OneOrMore<T> {
+    inner: OneOrMany<T>,
+}
+
+impl<T> OneOrMore<T> {
+    pub const fn new(first: T) -> Self {
+        Self {
+            inner: OneOrMany::Single(Some(first)),
+        }
+    }
+
+    // `len` can never be zero
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether this holds exactly one element
+    pub const fn is_one(&self) -> bool {
+        self.inner.is_one()
+    }
+
+    /// Whether this holds more than one element
+    pub const fn is_many(&self) -> bool {
+        self.inner.is_many()
+    }
+
+    pub fn first(&self) -> &T {
+        self.inner.first().unwrap()
+    }
+
+    pub fn first_mut(&mut self) -> &mut T {
+        self.inner.first_mut().unwrap()
+    }
+
+    pub fn last(&self) -> &T {
+        self.inner.last().unwrap()
+    }
+
+    pub fn last_mut(&mut self) -> &mut T {
+        self.inner.last_mut().unwrap()
+    }
+
+    /// Returns the first element, and the rest
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.inner.split_first().unwrap()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+
+    pub const fn as_one_or_many(&self) -> &OneOrMany<T> {
+        &self.inner
+    }
+
+    pub fn into_one_or_many(self) -> OneOrMany<T> {
+        self.inner
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner.into_vec()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.inner.push(item)
+    }
+
+    pub fn insert(&mut self, index: usize, item: T) {
+        self.inner.insert(index, item)
+    }
+
+    /// Removes the last element, unless it is the only one
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len() == 1 {
+            return None;
+        }
+        self.inner.pop()
+    }
+
+    /// Shortens this to `len` elements, but never below one
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len.max(1))
+    }
+
+    /// Folds every element into the first one
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.inner.into_iter().reduce(f).unwrap()
+    }
+}
+
+impl<T> core::ops::Deref for OneOrMore<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+// the slice can't change length, so this can't empty it
+impl<T> core::ops::DerefMut for OneOrMore<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for OneOrMore<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsMut<[T]> for OneOrMore<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T> core::borrow::Borrow<[T]> for OneOrMore<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsRef<OneOrMany<T>> for OneOrMore<T> {
+    fn as_ref(&self) -> &OneOrMany<T> {
+        &self.inner
+    }
+}
+
+impl<T, U> PartialEq<OneOrMany<U>> for OneOrMore<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OneOrMany<U>) -> bool {
+        self.inner == *other
+    }
+}
+
+impl<T, U> PartialEq<[U]> for OneOrMore<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for OneOrMore<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> PartialEq<Vec<U>> for OneOrMore<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T> Extend<T> for OneOrMore<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.inner.extend(iter)
+    }
+}
+
+impl<T> From<T> for OneOrMore<T> {
+    fn from(item: T) -> Self {
+        Self::new(item)
+    }
+}
+
+impl<T> TryFrom<OneOrMany<T>> for OneOrMore<T> {
+    type Error = OneOrMany<T>;
+
+    fn try_from(inner: OneOrMany<T>) -> Result<Self, Self::Error> {
+        if inner.is_empty() {
+            return Err(inner);
+        }
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<Vec<T>> for OneOrMore<T> {
+    type Error = Vec<T>;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from(OneOrMany::from(vec)).map_err(OneOrMany::into_vec)
+    }
+}
+
+impl<T> From<OneOrMore<T>> for OneOrMany<T> {
+    fn from(item: OneOrMore<T>) -> Self {
+        item.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<OneOrMore<T>> for Vec<T> {
+    fn from(item: OneOrMore<T>) -> Self {
+        item.into_vec()
+    }
+}
+
+impl<T> IntoIterator for OneOrMore<T> {
+    type Item = T;
+    type IntoIter = OneOrManyIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OneOrMore<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut OneOrMore<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::OneOrMany;
+
+/// An element of a [`OneOrMany`] failed to parse
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError<E> {
+    /// The position of the element, counting from 0
+    pub index: usize,
+    /// The trimmed piece of the input that failed to parse
+    pub piece: String,
+    pub error: E,
+}
+
+impl<E> fmt::Display for ParseError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            index,
+            piece,
+            error,
+        } = self;
+        write!(f, "invalid element {index} ({piece:?}): {error}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for ParseError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<T> OneOrMany<T>
+where
+    T: FromStr,
+{
+    /// Splits `input` on `separator` and parses each piece, trimmed of whitespace
+    ///
+    /// Empty (or blank) pieces are skipped, so `"a,,b,"` holds two elements. An `input` without any pieces gives an
+    /// empty `Single`, one piece a `Single` and more than one a `Many`. Unlike
+    /// [`serde::delimited`](crate::serde::delimited), a backslash doesn't escape anything.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `separator` is empty.
+    pub fn parse_with(input: &str, separator: &str) -> Result<Self, ParseError<T::Err>> {
+        assert!(!separator.is_empty(), "the separator cannot be empty");
+
+        let parse = |(index, piece): (usize, &str)| {
+            piece.parse().map_err(|error| ParseError {
+                index,
+                piece: piece.into(),
+                error,
+            })
+        };
+
+        let mut pieces = split_trimmed(input, separator).enumerate();
+        let first = pieces.next().map(parse).transpose()?;
+
+        let mut rest = pieces.peekable();
+        if rest.peek().is_none() {
+            return Ok(Self::Single(first));
+        }
+
+        let mut many = Vec::with_capacity(2);
+        many.extend(first);
+        for piece in rest {
+            many.push(parse(piece)?);
+        }
+        Ok(Self::Many(many.into()))
+    }
+}
+
+/// Parses a list separated by `,`, see [`OneOrMany::parse_with`]
+impl<T> FromStr for OneOrMany<T>
+where
+    T: FromStr,
+{
+    type Err = ParseError<T::Err>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, ",")
+    }
+}
+
+// the pieces of `input` between `separator`s, trimmed of whitespace, skipping the empty ones
+pub(crate) fn split_trimmed<'a>(
+    input: &'a str,
+    separator: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    input
+        .split(separator)
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+}
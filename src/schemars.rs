@@ -0,0 +1,125 @@
+//! JSON Schemas matching the [`serde`](crate::serde) format
+//!
+//! Every type is described as `anyOf` a bare value, an array of values, or `null` where that is accepted. This isn't a
+//! `oneOf`, as the element's own schema may also accept `null` or arrays. A missing field is only optional in the
+//! schema with `#[serde(default)]`.
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use alloc::{borrow::Cow, format, vec::Vec};
+
+use crate::{Between, OneOrMany, OneOrManyInline, OneOrMore};
+
+// a bare value if `min..=max` allows one element, a sequence of `min..=max` values, and `null` if it may be empty
+fn schema_for<T>(generator: &mut SchemaGenerator, min: usize, max: Option<usize>) -> Schema
+where
+    T: JsonSchema,
+{
+    let mut variants = Vec::new();
+    if min <= 1 && max.is_none_or(|max| max >= 1) {
+        variants.push(generator.subschema_for::<T>());
+    }
+
+    let mut seq = json_schema!({
+        "type": "array",
+        "items": generator.subschema_for::<T>(),
+    });
+    if min > 0 {
+        seq.insert("minItems".into(), min.into());
+    }
+    if let Some(max) = max {
+        seq.insert("maxItems".into(), max.into());
+    }
+    variants.push(seq);
+
+    if min == 0 {
+        variants.push(json_schema!({ "type": "null" }));
+    }
+
+    match variants.len() {
+        1 => variants.pop().unwrap(),
+        _ => json_schema!({ "anyOf": variants }),
+    }
+}
+
+impl<T> JsonSchema for OneOrMany<T>
+where
+    T: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("OneOrMany_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("one_or_many::OneOrMany<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        schema_for::<T>(generator, 0, None)
+    }
+}
+
+impl<T, const N: usize> JsonSchema for OneOrManyInline<T, N>
+where
+    T: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        OneOrMany::<T>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        OneOrMany::<T>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        OneOrMany::<T>::json_schema(generator)
+    }
+}
+
+impl<T> JsonSchema for OneOrMore<T>
+where
+    T: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("OneOrMore_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("one_or_many::OneOrMore<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        schema_for::<T>(generator, 1, None)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> JsonSchema for Between<T, MIN, MAX>
+where
+    T: JsonSchema,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Between_{MIN}_and_{MAX}_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("one_or_many::Between<{}, {MIN}, {MAX}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        schema_for::<T>(generator, MIN, Some(MAX))
+    }
+}
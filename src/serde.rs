@@ -0,0 +1,698 @@
+//! Serde support for [`OneOrMany`]
+//!
+//! By default a [`OneOrMany`] is read from a bare value, a sequence of values, or nothing at all (`null` or a missing field),
+//! and it is written as a bare value when it has exactly one element and as a sequence otherwise.
+//! [`OneOrManyInline`] and [`OneOrMore`] use the same format, though the latter rejects `null`, missing fields and
+//! empty sequences. [`Between`] (and its aliases) also use it, rejecting any number of elements outside of its bounds.
+//!
+//! The modules here can be used with `#[serde(with = "...")]` to pick a different output shape per field:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "one_or_many::serde::always_seq")]
+//!     hosts: OneOrMany<String>,
+//! }
+//! ```
+//!
+//! All of them deserialize the same way as the default. Note that serde only treats a missing field as empty
+//! for fields without `with`, so add `#[serde(default)]` alongside it if the field is optional.
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{
+        self,
+        value::{
+            BorrowedBytesDeserializer, BorrowedStrDeserializer, BytesDeserializer,
+            EnumAccessDeserializer, MapAccessDeserializer,
+        },
+        EnumAccess, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use alloc::{string::String, vec::Vec};
+
+use crate::{Between, OneOrMany, OneOrManyInline, OneOrMore};
+
+// a bare value, a sequence of values, or nothing at all (null or a missing field)
+//
+// written by hand rather than as an untagged enum, so the error of an element that fails to deserialize is kept
+struct OneOrManyVisitor<T>(PhantomData<fn() -> T>);
+
+impl<T> OneOrManyVisitor<T> {
+    const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    fn single<'de, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|one| OneOrMany::Single(Some(one)))
+    }
+}
+
+impl<'de, T> Visitor<'de> for OneOrManyVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = OneOrMany<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a single value or a sequence of values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // `size_hint::cautious` isn't public, so cap the hint like it does: to 1 MiB worth of elements, and to
+        // nothing for zero-sized ones
+        let cautious = match core::mem::size_of::<T>() {
+            0 => 0,
+            size => seq.size_hint().unwrap_or(0).min(1024 * 1024 / size),
+        };
+        let mut many = Vec::with_capacity(cautious);
+        while let Some(item) = seq.next_element()? {
+            many.push(item);
+        }
+        Ok(OneOrMany::Many(many.into()))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(OneOrMany::new())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(OneOrMany::new())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(v.into_deserializer())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(BytesDeserializer::new(v))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::single(BorrowedBytesDeserializer::new(v))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Self::single(MapAccessDeserializer::new(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        Self::single(EnumAccessDeserializer::new(data))
+    }
+}
+
+impl<T> Serialize for OneOrMany<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        scalar_if_one::serialize(self, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for OneOrMany<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // through `deserialize_option`, which is what serde hands a missing field to
+        deserializer.deserialize_option(OneOrManyVisitor::new())
+    }
+}
+
+impl<T, const N: usize> Serialize for OneOrManyInline<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for OneOrManyInline<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        OneOrMany::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T> Serialize for OneOrMore<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for OneOrMore<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = OneOrMany::deserialize(deserializer)?;
+        Self::try_from(inner).map_err(|empty| match empty {
+            OneOrMany::Single(..) => {
+                D::Error::custom("expected at least one value, found null or nothing")
+            }
+            OneOrMany::Many(..) => D::Error::invalid_length(0, &"at least one value"),
+        })
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Serialize for Between<T, MIN, MAX>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_slice(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, T, const MIN: usize, const MAX: usize> Deserialize<'de> for Between<T, MIN, MAX>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = OneOrMany::deserialize(deserializer)?;
+        Self::new(inner).map_err(D::Error::custom)
+    }
+}
+
+fn serialize_slice<T, S>(slice: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match slice {
+        [one] => one.serialize(serializer),
+        many => many.serialize(serializer),
+    }
+}
+
+/// Always writes a sequence, even for zero or one element
+pub mod always_seq {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &OneOrMany<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            OneOrMany::Single(one) => serializer.collect_seq(one),
+            OneOrMany::Many(many) => many.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        OneOrMany::deserialize(deserializer)
+    }
+}
+
+/// Writes a bare value when there is exactly one element, and a sequence otherwise
+///
+/// This is the default format.
+pub mod scalar_if_one {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &OneOrMany<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serialize_slice(value.as_slice(), serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        OneOrMany::deserialize(deserializer)
+    }
+}
+
+/// Writes back the shape that was read
+///
+/// `Single(Some(..))` is written as a bare value, `Single(None)` as `null` (or `None`) and `Many(..)` is always a sequence.
+pub mod preserve {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &OneOrMany<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match value {
+            OneOrMany::Single(Some(one)) => one.serialize(serializer),
+            OneOrMany::Single(None) => serializer.serialize_none(),
+            OneOrMany::Many(many) => many.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        OneOrMany::deserialize(deserializer)
+    }
+}
+
+/// Also reads a string of delimited values, for lists coming from environment variables or command lines
+///
+/// A string is split on the delimiter, each piece is trimmed, and empty pieces are skipped. A backslash escapes the
+/// next character, so `\,` is a literal comma and `\ ` is a space that isn't trimmed. Each piece is then deserialized
+/// into `T` from a string, parsing it for numbers, `bool` and `char`, the way environment variable formats do. Everything
+/// else is read like the default format, so this only works with self-describing formats.
+///
+/// Use [`comma`](delimited::comma) or [`whitespace`](delimited::whitespace) with `#[serde(with = "...")]`, or
+/// [`split`](delimited::split) with `#[serde(deserialize_with = "one_or_many::serde::delimited::split::<';', _, _>")]`
+/// for any other delimiter. All of them serialize in the default format.
+pub mod delimited {
+    use super::*;
+
+    /// Splits strings on `SEP`
+    pub fn split<'de, const SEP: char, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_split(deserializer, |c| c == SEP)
+    }
+
+    /// Splits strings on `,`
+    pub mod comma {
+        use super::*;
+
+        pub fn serialize<T, S>(value: &OneOrMany<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Serialize,
+            S: Serializer,
+        {
+            value.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+        where
+            T: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            split::<',', T, D>(deserializer)
+        }
+    }
+
+    /// Splits strings on runs of whitespace
+    pub mod whitespace {
+        use super::*;
+
+        pub fn serialize<T, S>(value: &OneOrMany<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Serialize,
+            S: Serializer,
+        {
+            value.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<OneOrMany<T>, D::Error>
+        where
+            T: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            deserialize_split(deserializer, char::is_whitespace)
+        }
+    }
+
+    fn deserialize_split<'de, T, D>(
+        deserializer: D,
+        is_sep: fn(char) -> bool,
+    ) -> Result<OneOrMany<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DelimitedVisitor {
+            is_sep,
+            _marker: PhantomData,
+        })
+    }
+
+    // splits on unescaped separators, trimming unescaped whitespace from both ends of each piece
+    fn split_escaped(input: &str, is_sep: fn(char) -> bool) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut piece = String::new();
+        // how much of `piece` ends in an escaped character, which trimming must keep
+        let mut keep = 0;
+
+        let mut chars = input.chars();
+        loop {
+            match chars.next() {
+                Some('\\') => {
+                    piece.push(chars.next().unwrap_or('\\'));
+                    keep = piece.len();
+                }
+                Some(c) if !is_sep(c) => {
+                    if !(piece.is_empty() && c.is_whitespace()) {
+                        piece.push(c)
+                    }
+                }
+                next => {
+                    piece.truncate(keep.max(piece.trim_end().len()));
+                    if !piece.is_empty() {
+                        pieces.push(core::mem::take(&mut piece));
+                    }
+                    keep = 0;
+
+                    if next.is_none() {
+                        break pieces;
+                    }
+                }
+            }
+        }
+    }
+
+    // hands a piece to `T` as a string, parsing it first when `T` asks for a number, a `bool` or a `char`
+    struct PieceDeserializer<'a, E> {
+        piece: &'a str,
+        _marker: PhantomData<E>,
+    }
+
+    impl<'a, E> PieceDeserializer<'a, E> {
+        const fn new(piece: &'a str) -> Self {
+            Self {
+                piece,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    macro_rules! parse_piece {
+        ($($method:ident => $visit:ident),* $(,)?) => {
+            $(
+                fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    visitor.$visit(self.piece.parse().map_err(E::custom)?)
+                }
+            )*
+        };
+    }
+
+    impl<'de, E> Deserializer<'de> for PieceDeserializer<'_, E>
+    where
+        E: de::Error,
+    {
+        type Error = E;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_str(self.piece)
+        }
+
+        parse_piece! {
+            deserialize_bool => visit_bool,
+            deserialize_i8 => visit_i8,
+            deserialize_i16 => visit_i16,
+            deserialize_i32 => visit_i32,
+            deserialize_i64 => visit_i64,
+            deserialize_i128 => visit_i128,
+            deserialize_u8 => visit_u8,
+            deserialize_u16 => visit_u16,
+            deserialize_u32 => visit_u32,
+            deserialize_u64 => visit_u64,
+            deserialize_u128 => visit_u128,
+            deserialize_f32 => visit_f32,
+            deserialize_f64 => visit_f64,
+            deserialize_char => visit_char,
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_newtype_struct<V>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_enum<V>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_enum(self.piece.into_deserializer())
+        }
+
+        ::serde::forward_to_deserialize_any! {
+            str string bytes byte_buf unit unit_struct seq tuple
+            tuple_struct map struct identifier ignored_any
+        }
+    }
+
+    struct DelimitedVisitor<T> {
+        is_sep: fn(char) -> bool,
+        _marker: PhantomData<fn() -> T>,
+    }
+
+    // everything but a string is read like the default format
+    macro_rules! forward_to_default {
+        ($($method:ident($ty:ty)),* $(,)?) => {
+            $(
+                fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    OneOrManyVisitor::new().$method(v)
+                }
+            )*
+        };
+    }
+
+    impl<'de, T> Visitor<'de> for DelimitedVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = OneOrMany<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a delimited string, a single value or a sequence of values")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            split_escaped(v, self.is_sep)
+                .iter()
+                .enumerate()
+                .map(|(index, piece)| {
+                    T::deserialize(PieceDeserializer::<E>::new(piece)).map_err(|err| {
+                        E::custom(format_args!("invalid element {index} ({piece:?}): {err}"))
+                    })
+                })
+                .collect()
+        }
+
+        forward_to_default! {
+            visit_bool(bool),
+            visit_i64(i64),
+            visit_i128(i128),
+            visit_u64(u64),
+            visit_u128(u128),
+            visit_f64(f64),
+            visit_char(char),
+            visit_bytes(&[u8]),
+            visit_borrowed_bytes(&'de [u8]),
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(OneOrMany::new())
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(OneOrMany::new())
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            OneOrManyVisitor::new().visit_seq(seq)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            OneOrManyVisitor::new().visit_map(map)
+        }
+
+        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+        where
+            A: EnumAccess<'de>,
+        {
+            OneOrManyVisitor::new().visit_enum(data)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! OpenAPI schemas matching the [`serde`](crate::serde) format
+//!
+//! Every type is described as `anyOf` a bare value, an array of values, or `null` where that is accepted. This isn't a
+//! `oneOf`, as the element's own schema may also accept `null` or arrays.
+use std::{borrow::Cow, format, vec::Vec};
+
+use ::utoipa::{
+    __dev::ComposeSchema,
+    openapi::{
+        schema::{AnyOfBuilder, ArrayBuilder, ObjectBuilder, Schema, Type},
+        RefOr,
+    },
+    ToSchema,
+};
+
+use crate::{Between, OneOrMany, OneOrManyInline, OneOrMore};
+
+// `#[derive(ToSchema)]` builds the schema of a generic field through `ComposeSchema`, passing in the schemas of the
+// type arguments, and `utoipa` implements `PartialSchema` for every `ComposeSchema`. `Vec` is implemented the same way.
+//
+// `ComposeSchema` lives in a hidden module that only exists with `utoipa`'s `macros` feature, which is why the
+// dependency enables that feature. It isn't covered by semver, so CI also tests against the latest 5.x release.
+fn schema_for<T>(mut generics: Vec<RefOr<Schema>>, min: usize, max: Option<usize>) -> RefOr<Schema>
+where
+    T: ComposeSchema,
+{
+    let item = if generics.is_empty() {
+        T::compose(generics)
+    } else {
+        generics.swap_remove(0)
+    };
+
+    let mut variants = Vec::new();
+    if min <= 1 && max.is_none_or(|max| max >= 1) {
+        variants.push(item.clone());
+    }
+
+    let seq = ArrayBuilder::new()
+        .items(item)
+        .min_items((min > 0).then_some(min))
+        .max_items(max);
+    variants.push(seq.into());
+
+    if min == 0 {
+        variants.push(ObjectBuilder::new().schema_type(Type::Null).into());
+    }
+
+    match variants.len() {
+        1 => variants.pop().unwrap(),
+        _ => variants
+            .into_iter()
+            .fold(AnyOfBuilder::new(), AnyOfBuilder::item)
+            .into(),
+    }
+}
+
+impl<T> ComposeSchema for OneOrMany<T>
+where
+    T: ComposeSchema,
+{
+    fn compose(generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        schema_for::<T>(generics, 0, None)
+    }
+}
+
+// the names include the type arguments, like the JSON Schema names do, so that registering two instantiations as
+// components doesn't give the same name twice. `#[derive(ToSchema)]` appends the arguments again when it names a field.
+impl<T> ToSchema for OneOrMany<T>
+where
+    T: ToSchema + ComposeSchema,
+{
+    fn name() -> Cow<'static, str> {
+        format!("OneOrMany_for_{}", T::name()).into()
+    }
+
+    fn schemas(schemas: &mut Vec<(std::string::String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+impl<T, const N: usize> ComposeSchema for OneOrManyInline<T, N>
+where
+    T: ComposeSchema,
+{
+    fn compose(generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        OneOrMany::<T>::compose(generics)
+    }
+}
+
+impl<T, const N: usize> ToSchema for OneOrManyInline<T, N>
+where
+    T: ToSchema + ComposeSchema,
+{
+    fn name() -> Cow<'static, str> {
+        OneOrMany::<T>::name()
+    }
+
+    fn schemas(schemas: &mut Vec<(std::string::String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+impl<T> ComposeSchema for OneOrMore<T>
+where
+    T: ComposeSchema,
+{
+    fn compose(generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        schema_for::<T>(generics, 1, None)
+    }
+}
+
+impl<T> ToSchema for OneOrMore<T>
+where
+    T: ToSchema + ComposeSchema,
+{
+    fn name() -> Cow<'static, str> {
+        format!("OneOrMore_for_{}", T::name()).into()
+    }
+
+    fn schemas(schemas: &mut Vec<(std::string::String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> ComposeSchema for Between<T, MIN, MAX>
+where
+    T: ComposeSchema,
+{
+    fn compose(generics: Vec<RefOr<Schema>>) -> RefOr<Schema> {
+        schema_for::<T>(generics, MIN, Some(MAX))
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> ToSchema for Between<T, MIN, MAX>
+where
+    T: ToSchema + ComposeSchema,
+{
+    fn name() -> Cow<'static, str> {
+        format!("Between_{MIN}_and_{MAX}_of_{}", T::name()).into()
+    }
+
+    fn schemas(schemas: &mut Vec<(std::string::String, RefOr<Schema>)>) {
+        T::schemas(schemas)
+    }
+}
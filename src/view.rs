@@ -0,0 +1,79 @@
+use crate::{Heap, OneOrMany};
+
+/// A borrowed [`OneOrMany`], by how many elements it holds rather than how they are stored
+///
+/// `Many` always has at least two elements. See [`OneOrMany::view`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum View<'a, T> {
+    Empty,
+    One(&'a T),
+    Many(&'a [T]),
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+/// A mutably borrowed [`OneOrMany`], by how many elements it holds rather than how they are stored
+///
+/// `Many` always has at least two elements. See [`OneOrMany::view_mut`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum ViewMut<'a, T> {
+    Empty,
+    One(&'a mut T),
+    Many(&'a mut [T]),
+}
+
+/// An owned [`OneOrMany`], by how many elements it holds rather than how they are stored
+///
+/// `Many` always has at least two elements. See [`OneOrMany::into_view`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OwnedView<T> {
+    Empty,
+    One(T),
+    Many(Heap<T>),
+}
+
+impl<T> OneOrMany<T> {
+    pub fn view(&self) -> View<'_, T> {
+        match self.as_slice() {
+            [] => View::Empty,
+            [one] => View::One(one),
+            many => View::Many(many),
+        }
+    }
+
+    pub fn view_mut(&mut self) -> ViewMut<'_, T> {
+        match self.as_mut_slice() {
+            [] => ViewMut::Empty,
+            [one] => ViewMut::One(one),
+            many => ViewMut::Many(many),
+        }
+    }
+
+    pub fn into_view(self) -> OwnedView<T> {
+        match self {
+            Self::Single(None) => OwnedView::Empty,
+            Self::Single(Some(one)) => OwnedView::One(one),
+            Self::Many(mut many) => match many.len() {
+                0 => OwnedView::Empty,
+                1 => OwnedView::One(many.pop().unwrap()),
+                _ => OwnedView::Many(many),
+            },
+        }
+    }
+}
+
+impl<T> From<OwnedView<T>> for OneOrMany<T> {
+    fn from(view: OwnedView<T>) -> Self {
+        match view {
+            OwnedView::Empty => Self::Single(None),
+            OwnedView::One(one) => Self::Single(Some(one)),
+            OwnedView::Many(many) => Self::Many(many),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::{AtMost, Between, CardinalityError, Exactly, OneOrMany};
+
+#[test]
+fn constructors() {
+    let list = Between::<i32, 1, 3>::new(OneOrMany::Many(vec![1, 2].into())).unwrap();
+    assert_eq!(list, [1, 2]);
+    assert!(list.is_many());
+
+    assert_eq!(
+        Between::<i32, 1, 3>::new(OneOrMany::new()),
+        Err(CardinalityError {
+            min: 1,
+            max: 3,
+            actual: 0
+        })
+    );
+    assert_eq!(
+        Between::<i32, 1, 3>::try_from(vec![1, 2, 3, 4])
+            .unwrap_err()
+            .actual,
+        4
+    );
+
+    assert!(Exactly::<i32, 2>::try_from(vec![1, 2]).is_ok());
+    assert!(Exactly::<i32, 2>::try_from(vec![1]).is_err());
+    assert!(AtMost::<i32, 1>::try_from(OneOrMany::new()).is_ok());
+    assert!(AtMost::<i32, 1>::try_from(vec![1, 2]).is_err());
+}
+
+#[test]
+fn push_and_pop() {
+    let mut list = Between::<i32, 1, 2>::try_from(vec![1]).unwrap();
+    assert_eq!(list.push(2), Ok(()));
+    assert_eq!(
+        list.push(3),
+        Err(CardinalityError {
+            min: 1,
+            max: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(list, [1, 2]);
+
+    assert_eq!(list.pop(), Some(2));
+    assert_eq!(list.pop(), None);
+    assert_eq!(list.into_inner(), OneOrMany::Single(Some(1)));
+}
+
+#[test]
+fn try_extend() {
+    let mut list = AtMost::<i32, 3>::try_from(vec![1]).unwrap();
+    assert_eq!(list.try_extend([2, 3, 4, 5]).unwrap_err().actual, 4);
+    assert_eq!(list, [1]);
+
+    list.try_extend([2, 3]).unwrap();
+    assert_eq!(list, [1, 2, 3]);
+
+    let mut list = Between::<i32, 2, 3>::try_from(vec![1, 2]).unwrap();
+    assert_eq!(list.try_extend(std::iter::repeat(0)).unwrap_err().actual, 4);
+    assert_eq!(list, [1, 2]);
+
+    let mut list = Between::<u32, 0, 2>::new(OneOrMany::from(1)).unwrap();
+    assert!(list.try_extend([2, 3]).is_err());
+    assert!(matches!(*list, OneOrMany::Single(Some(1))));
+
+    let mut list = AtMost::<u32, 1>::new(OneOrMany::new()).unwrap();
+    assert!(list.try_extend([1, 2]).is_err());
+    assert!(matches!(*list, OneOrMany::Single(None)));
+}
+
+#[test]
+fn try_extend_stops_past_max() {
+    let mut iter = 0..1_000_000;
+    let mut list = AtMost::<i32, 2>::try_from(OneOrMany::new()).unwrap();
+    assert!(list.try_extend(iter.by_ref()).is_err());
+    assert!(list.is_empty());
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+#[should_panic = "expected at most 2 elements, found 3"]
+fn extend_past_max() {
+    let mut list = AtMost::<i32, 2>::try_from(OneOrMany::new()).unwrap();
+    list.extend([1, 2, 3]);
+}
+
+#[test]
+fn error_display() {
+    let err = |min, max, actual| CardinalityError { min, max, actual }.to_string();
+    assert_eq!(err(2, 2, 1), "expected exactly 2 elements, found 1");
+    assert_eq!(err(0, 2, 3), "expected at most 2 elements, found 3");
+    assert_eq!(err(1, 3, 0), "expected between 1 and 3 elements, found 0");
+    assert_eq!(err(1, 1, 2), "expected exactly 1 element, found 2");
+    assert_eq!(err(0, 1, 2), "expected at most 1 element, found 2");
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use one_or_many::OneOrMany;
+
+// `Vec` doesn't promise a growth strategy, so count the (re)allocations made by the current thread instead
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let out = f();
+    (out, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn with_capacity() {
+    assert!(matches!(
+        OneOrMany::<i32>::with_capacity(1),
+        OneOrMany::Single(None)
+    ));
+
+    let list = OneOrMany::<i32>::with_capacity(10);
+    assert!(list.is_empty());
+    assert!(list.capacity() >= 10);
+    assert_eq!(OneOrMany::Single(Some(1)).capacity(), 1);
+}
+
+#[test]
+fn reserve() {
+    let mut list = OneOrMany::Single(Some(1));
+    list.reserve(0);
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+
+    list.reserve(10);
+    assert!(matches!(&list, OneOrMany::Many(v) if v == &[1]));
+    assert!(list.capacity() >= 11);
+
+    let mut list = OneOrMany::<i32>::new();
+    list.reserve_exact(1);
+    assert!(matches!(list, OneOrMany::Single(None)));
+    list.reserve_exact(3);
+    assert!(list.capacity() >= 3);
+
+    let mut list = OneOrMany::Single(Some(1));
+    assert!(list.try_reserve(3).is_ok());
+    assert_eq!(list, [1]);
+    assert!(list.try_reserve(usize::MAX).is_err());
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut list = OneOrMany::<i32>::with_capacity(10);
+    list.push(1);
+    list.shrink_to_fit();
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+
+    let mut list = OneOrMany::<i32>::with_capacity(10);
+    list.extend([1, 2]);
+    list.shrink_to_fit();
+    assert_eq!(list.capacity(), 2);
+}
+
+#[test]
+fn extend_allocates_once() {
+    let mut list = OneOrMany::Single(Some(0));
+    let ((), count) = allocations(|| list.extend(1..100));
+    assert_eq!(count, 1);
+    assert_eq!(list.len(), 100);
+    assert!(list.capacity() >= 100);
+
+    let (list, count) = allocations(|| (0..50).collect::<OneOrMany<_>>());
+    assert_eq!(count, 1);
+    assert!(list.capacity() >= 50);
+}
+
+#[test]
+fn spill_capacity() {
+    let mut pushed = OneOrMany::Single(Some(1));
+    pushed.push(2);
+
+    let mut inserted = OneOrMany::Single(Some(1));
+    inserted.insert(0, 2);
+    assert_eq!(inserted, [2, 1]);
+    assert_eq!(inserted.capacity(), pushed.capacity());
+
+    let ((), count) = allocations(|| {
+        inserted.push(3);
+        inserted.push(4);
+    });
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "clap")]
+
+use clap::{builder::TypedValueParser as _, ArgAction, Parser};
+use one_or_many::{
+    clap::{get_merged, merge, Merged, MergedArg, OneOrManyValueParser},
+    OneOrMany,
+};
+
+struct Tag;
+
+impl MergedArg for Tag {
+    fn arg() -> clap::Arg {
+        clap::Arg::new("tag").long("tag")
+    }
+}
+
+struct Host;
+
+impl MergedArg for Host {
+    fn arg() -> clap::Arg {
+        clap::Arg::new("host").long("host")
+    }
+
+    fn delimiter() -> Option<char> {
+        None
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    tag: Merged<String, Tag>,
+    #[command(flatten)]
+    host: Merged<String, Host>,
+    #[arg(long, value_parser = OneOrManyValueParser::new(clap::value_parser!(u16)).delimiter(Some(':')))]
+    port: Option<OneOrMany<u16>>,
+    #[arg(long)]
+    level: Vec<OneOrMany<String>>,
+}
+
+fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+    Args::try_parse_from(std::iter::once("test").chain(args.iter().copied()))
+}
+
+#[test]
+fn derive() {
+    let args = parse(&[]).unwrap();
+    assert!(matches!(*args.tag, OneOrMany::Single(None)));
+    assert!(args.port.is_none());
+
+    let args = parse(&["--tag", "a"]).unwrap();
+    assert!(args.tag.is_one());
+    assert_eq!(*args.tag, ["a"]);
+
+    let args = parse(&["--tag", "a, b,", "--port", "80:443"]).unwrap();
+    assert!(args.tag.is_many());
+    assert_eq!(*args.tag, ["a", "b"]);
+    assert_eq!(args.port.unwrap(), [80, 443]);
+}
+
+#[test]
+fn derive_occurrences() {
+    let args = parse(&["--tag", "a", "--tag", "b,c"]).unwrap();
+    assert!(args.tag.is_many());
+    assert_eq!(args.tag.into_inner(), ["a", "b", "c"]);
+
+    let args = parse(&["--host", "a,b", "--host", "c"]).unwrap();
+    assert_eq!(*args.host, ["a,b", "c"]);
+
+    let args = parse(&["--level", "debug", "--level", "info,warn"]).unwrap();
+    assert_eq!(merge(args.level), ["debug", "info", "warn"]);
+}
+
+#[test]
+fn derive_error() {
+    let err = parse(&["--port", "80:http"]).unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("'http'"), "{err}");
+}
+
+#[test]
+fn no_delimiter() {
+    let parser = OneOrManyValueParser::new(clap::builder::StringValueParser::new()).delimiter(None);
+    let list = parser
+        .parse_ref(&clap::Command::new("test"), None, "a,b".as_ref())
+        .unwrap();
+    assert!(list.is_one());
+    assert_eq!(list, ["a,b"]);
+
+    let list = parser
+        .parse_ref(&clap::Command::new("test"), None, "".as_ref())
+        .unwrap();
+    assert!(matches!(list, OneOrMany::Single(None)));
+}
+
+#[test]
+fn merged_occurrences() {
+    let cmd = clap::Command::new("test").arg(
+        clap::Arg::new("tag")
+            .long("tag")
+            .action(ArgAction::Append)
+            .value_parser(clap::value_parser!(OneOrMany<String>)),
+    );
+    let merged = |args: &[&str]| {
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(std::iter::once("test").chain(args.iter().copied()))
+            .unwrap();
+        get_merged::<String>(&matches, "tag").unwrap()
+    };
+
+    assert!(matches!(merged(&[]), OneOrMany::Single(None)));
+
+    let list = merged(&["--tag", "a"]);
+    assert!(list.is_one());
+    assert_eq!(list, ["a"]);
+
+    let list = merged(&["--tag", "a,b", "--tag", "c"]);
+    assert!(list.is_many());
+    assert_eq!(list, ["a", "b", "c"]);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn representation_independent_eq() {
+    assert_eq!(OneOrMany::Single(Some(1)), OneOrMany::Many(vec![1].into()));
+    assert_eq!(
+        OneOrMany::<i32>::Single(None),
+        OneOrMany::<i32>::Many(vec![].into())
+    );
+    assert_eq!(
+        OneOrMany::Many(vec![1, 2].into()),
+        OneOrMany::Many(vec![1, 2].into())
+    );
+
+    assert_ne!(OneOrMany::Single(Some(1)), OneOrMany::<i32>::Single(None));
+    assert_ne!(
+        OneOrMany::Single(Some(1)),
+        OneOrMany::Many(vec![1, 1].into())
+    );
+    assert_ne!(
+        OneOrMany::Many(vec![1, 2].into()),
+        OneOrMany::Many(vec![2, 1].into())
+    );
+}
+
+#[test]
+fn cross_type_eq() {
+    let list = OneOrMany::Many(vec![1, 2].into());
+    assert_eq!(list, [1, 2]);
+    assert_eq!(list, &[1, 2]);
+    assert_eq!(list, vec![1, 2]);
+    assert_eq!(list, [1, 2][..]);
+    assert_eq!(list, &[1, 2][..]);
+    assert_ne!(list, Some(1));
+
+    assert_eq!([1, 2], list);
+    assert_eq!(vec![1, 2], list);
+    assert_eq!([1, 2][..], list);
+    assert_eq!(&[1, 2][..], list);
+
+    let one = OneOrMany::Single(Some(1));
+    assert_eq!(one, Some(1));
+    assert_eq!(Some(1), one);
+    assert_eq!(one, [1]);
+    assert_eq!(OneOrMany::Many(vec![1].into()), Some(1));
+
+    let empty = OneOrMany::<i32>::new();
+    assert_eq!(empty, None::<i32>);
+    assert_eq!(empty, [0; 0]);
+    assert_eq!(None::<i32>, empty);
+
+    let strings = OneOrMany::Many(vec![String::from("a")].into());
+    assert_eq!(strings, ["a"]);
+}
+
+#[test]
+fn ordering() {
+    let mut list = vec![
+        OneOrMany::Many(vec![2].into()),
+        OneOrMany::Single(Some(1)),
+        OneOrMany::Many(vec![1, 2].into()),
+        OneOrMany::Single(None),
+    ];
+    list.sort();
+    assert_eq!(list, [vec![], vec![1], vec![1, 2], vec![2]]);
+
+    assert!(OneOrMany::Single(Some(1.0)) < OneOrMany::Many(vec![1.0, 0.0].into()));
+    assert_eq!(
+        OneOrMany::Single(Some(f64::NAN)).partial_cmp(&OneOrMany::Single(Some(1.0))),
+        None
+    );
+}
+
+#[test]
+fn hash() {
+    let mut map = HashMap::new();
+    map.insert(OneOrMany::Single(Some("a")), 1);
+    map.insert(OneOrMany::Many(vec!["a"].into()), 2);
+    map.insert(OneOrMany::Many(vec!["a", "b"].into()), 3);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&OneOrMany::Single(Some("a"))], 2);
+
+    // lookups through Borrow<[T]>
+    assert_eq!(map.get(&["a", "b"][..]), Some(&3));
+
+    let set = [OneOrMany::Single(None), OneOrMany::Many(vec![].into())]
+        .into_iter()
+        .collect::<HashSet<OneOrMany<i32>>>();
+    assert_eq!(set.len(), 1);
+
+    let set = [OneOrMany::Many(vec![1].into()), OneOrMany::Single(Some(1))]
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    assert_eq!(set.len(), 1);
+}
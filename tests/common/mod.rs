@@ -0,0 +1,227 @@
+// fixtures shared by the integration tests, not every test uses all of them
+#![allow(dead_code)]
+
+use one_or_many::{OneOrMany, OneOrManyInline};
+use proptest::prelude::*;
+
+/// Every way of representing zero to three elements
+pub fn representations() -> [OneOrMany<i32>; 6] {
+    [
+        OneOrMany::Single(None),
+        OneOrMany::Single(Some(1)),
+        OneOrMany::Many(vec![].into()),
+        OneOrMany::Many(vec![1].into()),
+        OneOrMany::Many(vec![1, 2].into()),
+        OneOrMany::Many(vec![1, 2, 3].into()),
+    ]
+}
+
+pub fn representation() -> impl Strategy<Value = OneOrMany<u8>> {
+    prop_oneof![
+        Just(OneOrMany::Single(None)),
+        any::<u8>().prop_map(|d| OneOrMany::Single(Some(d))),
+        prop::collection::vec(any::<u8>(), 0..8).prop_map(OneOrMany::from),
+    ]
+}
+
+#[derive(Clone, Debug)]
+pub enum Op {
+    Push(u8),
+    Pop,
+    Insert(usize, u8),
+    Remove(usize),
+    SwapRemove(usize),
+    Truncate(usize),
+    Clear,
+    Retain(u8),
+    RetainMut(u8),
+    Dedup,
+    DedupByKey(u8),
+    Drain(usize, usize),
+    SplitOff(usize),
+    Append(Vec<u8>),
+    Normalize,
+}
+
+pub fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        2 => any::<u8>().prop_map(Op::Push),
+        1 => Just(Op::Pop),
+        1 => (any::<usize>(), any::<u8>()).prop_map(|(i, d)| Op::Insert(i, d)),
+        1 => any::<usize>().prop_map(Op::Remove),
+        1 => any::<usize>().prop_map(Op::SwapRemove),
+        1 => (0..6_usize).prop_map(Op::Truncate),
+        1 => Just(Op::Clear),
+        1 => (1..4_u8).prop_map(Op::Retain),
+        1 => (1..4_u8).prop_map(Op::RetainMut),
+        1 => Just(Op::Dedup),
+        1 => prop_oneof![1..4_u8, Just(64)].prop_map(Op::DedupByKey),
+        1 => (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Drain(a, b)),
+        1 => any::<usize>().prop_map(Op::SplitOff),
+        1 => prop::collection::vec(any::<u8>(), 0..4).prop_map(Op::Append),
+        1 => Just(Op::Normalize),
+    ]
+}
+
+/// The mutations an [`Op`] is applied through
+pub trait List {
+    fn push(&mut self, item: u8);
+    fn pop(&mut self) -> Option<u8>;
+    fn insert(&mut self, index: usize, item: u8);
+    fn remove(&mut self, index: usize) -> u8;
+    fn swap_remove(&mut self, index: usize) -> u8;
+    fn truncate(&mut self, len: usize);
+    fn clear(&mut self);
+    fn retain(&mut self, keep: impl FnMut(&u8) -> bool);
+    fn retain_mut(&mut self, keep: impl FnMut(&mut u8) -> bool);
+    fn dedup(&mut self);
+    fn dedup_by_key(&mut self, key: impl FnMut(&mut u8) -> u8);
+    fn drain(&mut self, start: usize, end: usize) -> Vec<u8>;
+    fn split_off(&mut self, at: usize) -> Vec<u8>;
+    fn append(&mut self, other: Vec<u8>);
+    fn normalize(&mut self);
+}
+
+macro_rules! impl_list {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {$(
+        impl<$($generics)*> List for $ty {
+            fn push(&mut self, item: u8) {
+                <$ty>::push(self, item)
+            }
+            fn pop(&mut self) -> Option<u8> {
+                <$ty>::pop(self)
+            }
+            fn insert(&mut self, index: usize, item: u8) {
+                <$ty>::insert(self, index, item)
+            }
+            fn remove(&mut self, index: usize) -> u8 {
+                <$ty>::remove(self, index)
+            }
+            fn swap_remove(&mut self, index: usize) -> u8 {
+                <$ty>::swap_remove(self, index)
+            }
+            fn truncate(&mut self, len: usize) {
+                <$ty>::truncate(self, len)
+            }
+            fn clear(&mut self) {
+                <$ty>::clear(self)
+            }
+            fn retain(&mut self, keep: impl FnMut(&u8) -> bool) {
+                <$ty>::retain(self, keep)
+            }
+            fn retain_mut(&mut self, keep: impl FnMut(&mut u8) -> bool) {
+                <$ty>::retain_mut(self, keep)
+            }
+            fn dedup(&mut self) {
+                <$ty>::dedup(self)
+            }
+            fn dedup_by_key(&mut self, key: impl FnMut(&mut u8) -> u8) {
+                <$ty>::dedup_by_key(self, key)
+            }
+            fn drain(&mut self, start: usize, end: usize) -> Vec<u8> {
+                let drain = <$ty>::drain(self, start..end);
+                assert_eq!(drain.len(), end - start);
+                drain.collect()
+            }
+            fn split_off(&mut self, at: usize) -> Vec<u8> {
+                <$ty>::split_off(self, at).into_iter().collect()
+            }
+            fn append(&mut self, other: Vec<u8>) {
+                <$ty>::append(self, &mut other.into_iter().collect())
+            }
+            fn normalize(&mut self) {
+                <$ty>::normalize(self)
+            }
+        }
+    )*};
+}
+
+impl_list! {
+    [] OneOrMany<u8>,
+    [const N: usize] OneOrManyInline<u8, N>,
+}
+
+/// Applies `op` to both `list` and the `Vec` model, returning whatever each produced
+pub fn apply(op: &Op, list: &mut impl List, model: &mut Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+    // keep indices within bounds, the panicking paths are tested separately
+    let index = |i: usize, len: usize| i % (len + 1);
+
+    match op.clone() {
+        Op::Push(d) => {
+            list.push(d);
+            model.push(d);
+            (vec![], vec![])
+        }
+        Op::Pop => (
+            list.pop().into_iter().collect(),
+            model.pop().into_iter().collect(),
+        ),
+        Op::Insert(i, d) => {
+            let i = index(i, model.len());
+            list.insert(i, d);
+            model.insert(i, d);
+            (vec![], vec![])
+        }
+        Op::Remove(i) if !model.is_empty() => {
+            let i = i % model.len();
+            (vec![list.remove(i)], vec![model.remove(i)])
+        }
+        Op::SwapRemove(i) if !model.is_empty() => {
+            let i = i % model.len();
+            (vec![list.swap_remove(i)], vec![model.swap_remove(i)])
+        }
+        Op::Remove(..) | Op::SwapRemove(..) => (vec![], vec![]),
+        Op::Truncate(len) => {
+            list.truncate(len);
+            model.truncate(len);
+            (vec![], vec![])
+        }
+        Op::Clear => {
+            list.clear();
+            model.clear();
+            (vec![], vec![])
+        }
+        Op::Retain(m) => {
+            list.retain(|d| d.is_multiple_of(m));
+            model.retain(|d| d.is_multiple_of(m));
+            (vec![], vec![])
+        }
+        Op::RetainMut(m) => {
+            let mut f = |d: &mut u8| {
+                *d = d.wrapping_add(1);
+                d.is_multiple_of(m)
+            };
+            list.retain_mut(&mut f);
+            model.retain_mut(f);
+            (vec![], vec![])
+        }
+        Op::Dedup => {
+            list.dedup();
+            model.dedup();
+            (vec![], vec![])
+        }
+        Op::DedupByKey(m) => {
+            list.dedup_by_key(|d| *d / m);
+            model.dedup_by_key(|d| *d / m);
+            (vec![], vec![])
+        }
+        Op::Drain(a, b) => {
+            let (a, b) = (index(a, model.len()), index(b, model.len()));
+            let (start, end) = (a.min(b), a.max(b));
+            (list.drain(start, end), model.drain(start..end).collect())
+        }
+        Op::SplitOff(at) => {
+            let at = index(at, model.len());
+            (list.split_off(at), model.split_off(at))
+        }
+        Op::Append(other) => {
+            list.append(other.clone());
+            model.extend(other);
+            (vec![], vec![])
+        }
+        Op::Normalize => {
+            list.normalize();
+            (vec![], vec![])
+        }
+    }
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use std::collections::VecDeque;
+
+use common::representation;
+use one_or_many::OneOrMany;
+use proptest::prelude::*;
+
+// checks every observer against the `Vec` model
+fn check<T>(list: &OneOrMany<T>, model: &[T])
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(list.len(), model.len(), "len of {list:?}");
+    assert_eq!(list.is_empty(), model.is_empty(), "is_empty of {list:?}");
+    assert_eq!(list.is_one(), model.len() == 1, "is_one of {list:?}");
+    assert_eq!(list.is_many(), model.len() > 1, "is_many of {list:?}");
+    assert_eq!(list.as_slice(), model);
+    assert_eq!(
+        list.iter().collect::<Vec<_>>(),
+        model.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn new_and_default() {
+    check(&OneOrMany::<i32>::new(), &[]);
+    check(&OneOrMany::<i32>::default(), &[]);
+    assert!(matches!(OneOrMany::<i32>::new(), OneOrMany::Single(None)));
+}
+
+#[test]
+fn representations() {
+    check(&OneOrMany::<i32>::Single(None), &[]);
+    check(&OneOrMany::Single(Some(1)), &[1]);
+    check(&OneOrMany::<i32>::Many(vec![].into()), &[]);
+    check(&OneOrMany::Many(vec![1].into()), &[1]);
+    check(&OneOrMany::Many(vec![1, 2].into()), &[1, 2]);
+}
+
+#[test]
+fn is_empty() {
+    assert!(OneOrMany::<i32>::Single(None).is_empty());
+    assert!(OneOrMany::<i32>::Many(vec![].into()).is_empty());
+    assert!(!OneOrMany::Single(Some(1)).is_empty());
+    assert!(!OneOrMany::Many(vec![1].into()).is_empty());
+    assert!(!OneOrMany::Many(vec![1, 2].into()).is_empty());
+}
+
+#[test]
+fn push_transitions() {
+    let mut list = OneOrMany::new();
+    list.push(1);
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+
+    list.push(2);
+    assert!(matches!(&list, OneOrMany::Many(v) if v == &[1, 2]));
+
+    let mut list = OneOrMany::Many(vec![].into());
+    list.push(1);
+    assert!(matches!(&list, OneOrMany::Many(v) if v == &[1]));
+}
+
+#[test]
+fn from_impls() {
+    check(&OneOrMany::from(1), &[1]);
+    check(&OneOrMany::from(Some(1)), &[1]);
+    check(&OneOrMany::<i32>::from(None), &[]);
+    check(&OneOrMany::<i32>::from(vec![]), &[]);
+    check(&OneOrMany::<i32>::from(vec![1]), &[1]);
+    check(&OneOrMany::<i32>::from(vec![1, 2]), &[1, 2]);
+    check(
+        &OneOrMany::<i32>::from(vec![1, 2].into_boxed_slice()),
+        &[1, 2],
+    );
+    check(&OneOrMany::<i32>::from(VecDeque::from([1, 2])), &[1, 2]);
+}
+
+#[test]
+fn from_iter_shape() {
+    let list = std::iter::empty::<i32>().collect::<OneOrMany<_>>();
+    assert!(matches!(list, OneOrMany::Single(None)));
+
+    let list = std::iter::once(1).collect::<OneOrMany<_>>();
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+
+    let list = [1, 2].into_iter().collect::<OneOrMany<_>>();
+    assert!(matches!(list, OneOrMany::Many(..)));
+}
+
+proptest! {
+    #[test]
+    fn push(list in representation(), items in prop::collection::vec(any::<u8>(), 0..8)) {
+        let mut list = list;
+        let mut model = list.as_slice().to_vec();
+        check(&list, &model);
+
+        for item in items {
+            list.push(item);
+            model.push(item);
+            check(&list, &model);
+        }
+    }
+
+    #[test]
+    fn extend(list in representation(), items in prop::collection::vec(any::<u8>(), 0..8)) {
+        let mut list = list;
+        let mut model = list.as_slice().to_vec();
+
+        list.extend(items.iter().copied());
+        model.extend(items.iter().copied());
+        check(&list, &model);
+    }
+
+    #[test]
+    fn from_iter(items in prop::collection::vec(any::<u8>(), 0..8)) {
+        let list = items.iter().copied().collect::<OneOrMany<_>>();
+        check(&list, &items);
+        prop_assert_eq!(matches!(list, OneOrMany::Single(..)), items.len() <= 1);
+    }
+
+    #[test]
+    fn from_vec(items in prop::collection::vec(any::<u8>(), 0..8)) {
+        check(&OneOrMany::from(items.clone()), &items);
+        check(&OneOrMany::from(items.clone().into_boxed_slice()), &items);
+        check(&OneOrMany::from(VecDeque::from(items.clone())), &items);
+    }
+
+    #[test]
+    fn from_option(item in any::<Option<u8>>()) {
+        check(&OneOrMany::from(item), item.as_slice());
+    }
+
+    #[test]
+    fn into_iter(list in representation()) {
+        let model = list.as_slice().to_vec();
+        prop_assert_eq!(list.clone().into_iter().collect::<Vec<_>>(), model.clone());
+        prop_assert_eq!(list.into_vec(), model);
+    }
+}
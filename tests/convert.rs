@@ -0,0 +1,99 @@
+#![cfg(feature = "alloc")]
+
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn into_vec() {
+    assert_eq!(OneOrMany::<i32>::Single(None).into_vec(), Vec::<i32>::new());
+    assert_eq!(OneOrMany::Single(Some(1)).into_vec(), [1]);
+    assert_eq!(OneOrMany::Many(vec![1, 2].into()).into_vec(), [1, 2]);
+    assert_eq!(Vec::from(OneOrMany::Single(Some(1))), [1]);
+}
+
+#[test]
+fn into_single() {
+    assert_eq!(OneOrMany::Single(Some(1)).into_single(), Ok(1));
+    assert_eq!(OneOrMany::Many(vec![1].into()).into_single(), Ok(1));
+
+    assert!(matches!(
+        OneOrMany::<i32>::Single(None).into_single(),
+        Err(OneOrMany::Single(None))
+    ));
+    assert!(matches!(
+        OneOrMany::Many(vec![1, 2].into()).into_single(),
+        Err(OneOrMany::Many(v)) if v == [1, 2]
+    ));
+}
+
+#[test]
+fn into_option() {
+    assert_eq!(OneOrMany::<i32>::Single(None).into_option(), Ok(None));
+    assert_eq!(OneOrMany::Single(Some(1)).into_option(), Ok(Some(1)));
+    assert_eq!(
+        OneOrMany::<i32>::Many(vec![].into()).into_option(),
+        Ok(None)
+    );
+    assert_eq!(OneOrMany::Many(vec![1].into()).into_option(), Ok(Some(1)));
+    assert!(matches!(
+        OneOrMany::Many(vec![1, 2].into()).into_option(),
+        Err(OneOrMany::Many(v)) if v == [1, 2]
+    ));
+
+    assert_eq!(
+        Option::try_from(OneOrMany::Many(vec![1].into())),
+        Ok(Some(1))
+    );
+    assert!(Option::<i32>::try_from(OneOrMany::Many(vec![1, 2].into())).is_err());
+}
+
+#[test]
+fn try_into_array() {
+    assert_eq!(<[i32; 1]>::try_from(OneOrMany::Single(Some(1))), Ok([1]));
+    assert_eq!(
+        <[i32; 2]>::try_from(OneOrMany::Many(vec![1, 2].into())),
+        Ok([1, 2])
+    );
+    assert_eq!(<[i32; 0]>::try_from(OneOrMany::Single(None)), Ok([]));
+
+    assert!(matches!(
+        <[i32; 2]>::try_from(OneOrMany::Single(Some(1))),
+        Err(OneOrMany::Single(Some(1)))
+    ));
+}
+
+#[test]
+fn into_other_containers() {
+    let boxed: Box<[i32]> = OneOrMany::Single(Some(1)).into();
+    assert_eq!(&*boxed, [1]);
+
+    let deque: VecDeque<i32> = OneOrMany::Many(vec![1, 2].into()).into();
+    assert_eq!(deque, [1, 2]);
+
+    let rc: Rc<[i32]> = OneOrMany::Many(vec![1, 2].into()).into();
+    assert_eq!(&*rc, [1, 2]);
+
+    let arc: Arc<[i32]> = OneOrMany::<i32>::Single(None).into();
+    assert!(arc.is_empty());
+}
+
+#[test]
+fn from_other_containers() {
+    let list = OneOrMany::<i32>::from(vec![1, 2].into_boxed_slice());
+    assert_eq!(list, [1, 2]);
+
+    let list = OneOrMany::<i32>::from(VecDeque::from([1, 2]));
+    assert_eq!(list, [1, 2]);
+}
+
+#[test]
+fn heap_is_a_vec() {
+    let OneOrMany::Many(mut many) = OneOrMany::from(vec![1, 2]) else {
+        unreachable!()
+    };
+    many.push(3);
+    assert_eq!(many, [1, 2, 3]);
+    assert!(many.capacity() >= 3);
+    assert_eq!(many.into_vec(), vec![1, 2, 3]);
+}
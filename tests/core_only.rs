@@ -0,0 +1,113 @@
+#![cfg(not(feature = "alloc"))]
+
+use one_or_many::{AtMost, CardinalityError, OneOrMany};
+
+#[test]
+fn fixed_capacity() {
+    let mut list = OneOrMany::new();
+    assert!(list.is_empty());
+
+    list.push(1);
+    assert!(list.is_one());
+    list.extend([2, 3, 4]);
+    assert!(list.is_many());
+    assert_eq!(list, [1, 2, 3, 4]);
+    assert_eq!(list.capacity(), 4);
+    assert_eq!(list.try_push(5), Err(5));
+
+    assert_eq!(list.remove(3), 4);
+    list.insert(0, 0);
+    assert_eq!(list.drain(1..3).collect::<OneOrMany<_>>(), [1, 2]);
+    assert_eq!(list, [0, 3]);
+    assert_eq!(list.into_iter().next_back(), Some(3));
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(OneOrMany::from(1).into_single(), Ok(1));
+    assert_eq!(OneOrMany::from(Some(1)).into_option(), Ok(Some(1)));
+    assert_eq!(<[i32; 1]>::try_from(OneOrMany::from(1)), Ok([1]));
+    assert!(<[i32; 0]>::try_from(OneOrMany::from(1)).is_err());
+}
+
+#[test]
+#[should_panic = "cannot push more than 4 elements without the `alloc` feature"]
+fn push_past_capacity() {
+    let mut list = OneOrMany::from(1);
+    list.extend([2, 3, 4]);
+    list.push(5);
+}
+
+#[test]
+#[should_panic = "cannot push more than 4 elements without the `alloc` feature"]
+fn collect_past_capacity() {
+    let _: OneOrMany<i32> = (0..5).collect();
+}
+
+#[test]
+fn inline_fixed_capacity() {
+    use one_or_many::OneOrManyInline;
+
+    let mut list = OneOrManyInline::<_, 2>::new();
+    list.push(1);
+    list.push(2);
+    assert_eq!(list.try_push(3), Err(3));
+    assert_eq!(list, [1, 2]);
+
+    assert_eq!(list.remove(0), 1);
+    list.insert(0, 0);
+    assert_eq!(
+        list.drain(..).rev().collect::<OneOrManyInline<_, 2>>(),
+        [2, 0]
+    );
+}
+
+#[test]
+fn one_or_more_single() {
+    use one_or_many::OneOrMore;
+
+    let mut list = OneOrMore::new(1);
+    *list.first_mut() += 1;
+    assert_eq!(*list.last(), 2);
+    assert_eq!(list.pop(), None);
+    assert!(OneOrMore::<i32>::try_from(OneOrMany::new()).is_err());
+}
+
+#[test]
+fn many_is_inline() {
+    // this match compiles the same way with `alloc`
+    let describe = |list: &OneOrMany<i32>| match list {
+        OneOrMany::Single(None) => "empty",
+        OneOrMany::Single(Some(..)) => "one",
+        OneOrMany::Many(many) if many.len() <= 1 => "denormalized",
+        OneOrMany::Many(..) => "many",
+    };
+    assert_eq!(describe(&OneOrMany::new()), "empty");
+    assert_eq!(describe(&OneOrMany::from(1)), "one");
+    assert_eq!(describe(&[1, 2].into_iter().collect()), "many");
+
+    let mut list: OneOrMany<_> = [1, 2].into_iter().collect();
+    list.pop();
+    assert_eq!(describe(&list), "denormalized");
+    list.normalize();
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+}
+
+#[test]
+fn bounded_push() {
+    let mut list = AtMost::<i32, 3>::try_from(OneOrMany::new()).unwrap();
+    list.try_extend([1, 2, 3]).unwrap();
+    assert_eq!(
+        list.push(4),
+        Err(CardinalityError {
+            min: 0,
+            max: 3,
+            actual: 4
+        })
+    );
+
+    // past the four elements a `OneOrMany` has room for, the error reports that instead of `MAX`
+    let mut list = AtMost::<i32, 8>::try_from(OneOrMany::new()).unwrap();
+    assert_eq!(list.try_extend(0..8).unwrap_err().max, 4);
+    assert!(list.is_empty());
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::{OneOrMany, OneOrMore, ParseError};
+
+#[test]
+fn display() {
+    assert_eq!(OneOrMany::<i32>::new().to_string(), "");
+    assert_eq!(OneOrMany::<i32>::from(1).to_string(), "1");
+    assert_eq!(OneOrMany::<i32>::from(vec![1]).to_string(), "1");
+    assert_eq!(OneOrMany::<i32>::from(vec![1, 2, 3]).to_string(), "1, 2, 3");
+
+    let list = OneOrMany::<f32>::from(vec![1.0, 2.5]);
+    assert_eq!(format!("{list:.2}"), "1.00, 2.50");
+
+    let list = OneOrMore::new("a");
+    assert_eq!(list.to_string(), "a");
+}
+
+#[test]
+fn display_with() {
+    let list = OneOrMany::<&str>::from(vec!["a", "b", "c"]);
+    assert_eq!(list.display_with(", ", " and ").to_string(), "a, b and c");
+    assert_eq!(list.display_with(" | ", " | ").to_string(), "a | b | c");
+
+    let list = OneOrMany::<&str>::from(vec!["a", "b"]);
+    assert_eq!(list.display_with(", ", " or ").to_string(), "a or b");
+
+    let list = OneOrMany::<&str>::from("a");
+    assert_eq!(list.display_with(", ", " and ").to_string(), "a");
+}
+
+#[test]
+fn from_str() {
+    let list: OneOrMany<u16> = "".parse().unwrap();
+    assert!(matches!(list, OneOrMany::Single(None)));
+
+    let list: OneOrMany<u16> = "80".parse().unwrap();
+    assert!(list.is_one());
+    assert_eq!(list, [80]);
+
+    let list: OneOrMany<u16> = "80, 443,8080".parse().unwrap();
+    assert!(list.is_many());
+    assert_eq!(list, [80, 443, 8080]);
+
+    let list = OneOrMany::<String>::parse_with("a; b c ;d", ";").unwrap();
+    assert_eq!(list, ["a", "b c", "d"]);
+
+    // empty pieces are skipped, like `serde::delimited` does
+    let list: OneOrMany<u16> = "80,, 443,".parse().unwrap();
+    assert!(list.is_many());
+    assert_eq!(list, [80, 443]);
+
+    let list: OneOrMany<u16> = " , ,".parse().unwrap();
+    assert!(matches!(list, OneOrMany::Single(None)));
+}
+
+#[test]
+fn from_str_error() {
+    let err = "80, http ,443".parse::<OneOrMany<u16>>().unwrap_err();
+    assert_eq!(err.index, 1);
+    assert_eq!(err.piece, "http");
+    assert_eq!(err.error, "http".parse::<u16>().unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        r#"invalid element 1 ("http"): invalid digit found in string"#
+    );
+
+    assert!(matches!(
+        "80,,-1".parse::<OneOrMany<u16>>(),
+        Err(ParseError { index: 1, .. })
+    ));
+}
+
+#[test]
+fn round_trip() {
+    let list = OneOrMany::<u16>::from(vec![1, 2, 3]);
+    assert_eq!(list.to_string().parse::<OneOrMany<u16>>().unwrap(), list);
+}
+
+#[test]
+#[should_panic = "the separator cannot be empty"]
+fn parse_with_empty_separator() {
+    let _ = OneOrMany::<String>::parse_with("ab", "");
+}
+
+#[test]
+fn display_inline() {
+    use one_or_many::OneOrManyInline;
+
+    let list = OneOrManyInline::<i32, 2>::from(vec![1, 2, 3]);
+    assert_eq!(list.to_string(), "1, 2, 3");
+    assert_eq!(list.display_with(", ", " and ").to_string(), "1, 2 and 3");
+    assert_eq!(OneOrManyInline::<i32, 2>::from(1).to_string(), "1");
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn get_or_insert() {
+    let mut list = OneOrMany::new();
+    *list.get_or_insert_with(|| 1) += 1;
+    assert!(matches!(list, OneOrMany::Single(Some(2))));
+
+    assert_eq!(*list.first_or_insert(5), 2);
+    assert_eq!(*list.get_or_insert_with(|| unreachable!()), 2);
+
+    let mut list = OneOrMany::<i32>::Many(vec![].into());
+    assert_eq!(*list.first_or_insert(5), 5);
+    assert!(matches!(list, OneOrMany::Many(v) if v == [5]));
+}
+
+#[test]
+fn replace_take_set() {
+    let mut list = OneOrMany::Many(vec![1, 2].into());
+    assert!(matches!(list.replace(3), OneOrMany::Many(v) if v == [1, 2]));
+    assert!(matches!(list, OneOrMany::Single(Some(3))));
+
+    assert!(matches!(list.take(), OneOrMany::Single(Some(3))));
+    assert!(matches!(list, OneOrMany::Single(None)));
+
+    list.extend([1, 2]);
+    list.set(4);
+    assert!(matches!(list, OneOrMany::Single(Some(4))));
+
+    let mut list = OneOrMany::Many(vec![1, 2].into());
+    assert_eq!(std::mem::take(&mut list), [1, 2]);
+    assert!(matches!(list, OneOrMany::Single(None)));
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn index() {
+    let list = OneOrMany::Single(Some(1));
+    assert_eq!(list[0], 1);
+    assert_eq!(list[..], [1]);
+    assert_eq!(list[1..], [0; 0]);
+    assert_eq!(list[..=0], [1]);
+
+    let list = OneOrMany::Many(vec![1, 2, 3].into());
+    assert_eq!(list[2], 3);
+    assert_eq!(list[1..], [2, 3]);
+    assert_eq!(list[..2], [1, 2]);
+    assert_eq!(list[1..=1], [2]);
+    assert_eq!(list[..=1], [1, 2]);
+    assert_eq!(
+        list[(std::ops::Bound::Excluded(0), std::ops::Bound::Unbounded)],
+        [2, 3]
+    );
+}
+
+#[test]
+fn index_mut() {
+    let mut list = OneOrMany::Single(Some(1));
+    list[0] = 2;
+    assert_eq!(list, [2]);
+
+    let mut list = OneOrMany::Many(vec![1, 2, 3].into());
+    list[1..].fill(0);
+    list[0] += 1;
+    assert_eq!(list, [2, 0, 0]);
+}
+
+#[test]
+#[should_panic = "index out of bounds: the len is 1 but the index is 1"]
+fn index_out_of_bounds() {
+    let list = OneOrMany::Single(Some(1));
+    let _ = list[1];
+}
+
+#[test]
+#[should_panic]
+fn index_empty() {
+    let list = OneOrMany::<i32>::new();
+    let _ = list[0];
+}
+
+#[test]
+fn get() {
+    for list in [OneOrMany::Single(Some(1)), OneOrMany::Many(vec![1].into())] {
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), None);
+        assert_eq!(list.get(..), Some(&[1][..]));
+        assert_eq!(list.get(..2), None);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&1));
+    }
+
+    for list in [
+        OneOrMany::<i32>::Single(None),
+        OneOrMany::Many(vec![].into()),
+    ] {
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get(..), Some(&[][..]));
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+    }
+
+    let list = OneOrMany::Many(vec![1, 2, 3].into());
+    assert_eq!(list.get(1..), Some(&[2, 3][..]));
+    assert_eq!(list.first(), Some(&1));
+    assert_eq!(list.last(), Some(&3));
+}
+
+#[test]
+fn get_mut() {
+    for mut list in [OneOrMany::Single(Some(1)), OneOrMany::Many(vec![1].into())] {
+        *list.get_mut(0).unwrap() += 1;
+        *list.first_mut().unwrap() += 1;
+        *list.last_mut().unwrap() += 1;
+        assert!(list.get_mut(1).is_none());
+        assert_eq!(list, [4]);
+    }
+
+    let mut list = OneOrMany::Many(vec![1, 2, 3].into());
+    list.get_mut(1..).unwrap().fill(0);
+    *list.last_mut().unwrap() = 4;
+    assert_eq!(list, [1, 0, 4]);
+
+    let mut list = OneOrMany::<i32>::new();
+    assert!(list.first_mut().is_none());
+    assert!(list.last_mut().is_none());
+}
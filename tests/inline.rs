@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use std::rc::Rc;
+
+use common::{apply, op, Op};
+use one_or_many::{OneOrMany, OneOrManyInline};
+use proptest::prelude::*;
+
+type Inline = OneOrManyInline<u8, 3>;
+
+proptest! {
+    #[test]
+    fn matches_vec(ops in prop::collection::vec(op(), 0..48)) {
+        let mut list = Inline::new();
+        let mut model = vec![];
+
+        for op in &ops {
+            let (left, right) = apply(op, &mut list, &mut model);
+            prop_assert_eq!(left, right, "{:?}", op);
+            prop_assert_eq!(list.as_slice(), model.as_slice(), "{:?}", op);
+            prop_assert_eq!(list.len(), model.len());
+            prop_assert_eq!(list.is_one(), model.len() == 1);
+            prop_assert_eq!(list.is_many(), model.len() > 1);
+            if let Op::Normalize = op {
+                prop_assert_eq!(list.is_inline(), model.len() <= 3);
+            }
+            if model.len() > 3 {
+                prop_assert!(!list.is_inline());
+            }
+        }
+
+        prop_assert_eq!(list.clone().into_iter().rev().collect::<Vec<_>>(), model.iter().rev().copied().collect::<Vec<_>>());
+        prop_assert_eq!(OneOrMany::<u8>::from(list.clone()), model.clone());
+        prop_assert_eq!(list.into_vec(), model);
+    }
+}
+
+#[test]
+fn spills_past_capacity() {
+    let mut list = OneOrManyInline::<_, 2>::new();
+    list.push(1);
+    list.push(2);
+    assert!(list.is_inline());
+
+    list.push(3);
+    assert!(!list.is_inline());
+    assert_eq!(list, [1, 2, 3]);
+
+    list.pop();
+    assert!(!list.is_inline());
+    list.normalize();
+    assert!(list.is_inline());
+    assert_eq!(list, [1, 2]);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut list = OneOrManyInline::<_, 0>::new();
+    assert!(list.is_inline());
+    list.push(1);
+    assert!(!list.is_inline());
+    assert_eq!(list, [1]);
+}
+
+#[test]
+fn conversions() {
+    let list = OneOrManyInline::<i32, 2>::from(vec![1, 2]);
+    assert!(list.is_inline());
+    assert_eq!(OneOrMany::<i32>::from(list), [1, 2]);
+
+    let list = OneOrManyInline::<i32, 2>::from(OneOrMany::Many(vec![1, 2, 3].into()));
+    assert!(!list.is_inline());
+
+    assert_eq!(OneOrManyInline::<_, 2>::from(1).into_single(), Ok(1));
+    assert_eq!(OneOrManyInline::<i32, 2>::new().into_option(), Ok(None));
+    assert!(matches!(
+        OneOrMany::from(OneOrManyInline::<_, 2>::from(1)),
+        OneOrMany::Single(Some(1))
+    ));
+}
+
+#[test]
+fn shares_slice_api() {
+    let mut list = [3, 1, 2].into_iter().collect::<OneOrManyInline<_, 4>>();
+    list.sort();
+    assert_eq!(list[..], [1, 2, 3]);
+    assert_eq!(list.first(), Some(&1));
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    list.iter_mut().for_each(|d| *d *= 2);
+    assert_eq!(list, OneOrMany::Many(vec![2, 4, 6].into()));
+    assert_eq!(format!("{list:?}"), "[2, 4, 6]");
+}
+
+#[test]
+fn drops_every_element_once() {
+    let item = Rc::new(());
+
+    let mut list = OneOrManyInline::<_, 2>::new();
+    list.extend(std::iter::repeat_with(|| item.clone()).take(2));
+    assert_eq!(Rc::strong_count(&item), 3);
+
+    let clone = list.clone();
+    assert_eq!(Rc::strong_count(&item), 5);
+    drop(clone);
+
+    let mut iter = list.clone().into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&item), 3);
+
+    list.push(item.clone());
+    drop(list.drain(1..));
+    assert_eq!(Rc::strong_count(&item), 2);
+
+    list.truncate(0);
+    assert_eq!(Rc::strong_count(&item), 1);
+
+    list.extend(std::iter::repeat_with(|| item.clone()).take(2));
+    drop(list);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
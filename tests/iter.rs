@@ -0,0 +1,143 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::representations;
+use one_or_many::OneOrMany;
+
+fn expected(list: &OneOrMany<i32>) -> Vec<i32> {
+    list.clone().into_iter().collect()
+}
+
+#[test]
+fn iter() {
+    for list in representations() {
+        let expected = expected(&list);
+
+        let iter = list.iter();
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(iter.as_slice(), expected);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), expected);
+
+        let reversed = expected.iter().rev().collect::<Vec<_>>();
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), reversed);
+
+        assert_eq!((&list).into_iter().count(), expected.len());
+        assert_eq!(list.iter().last(), expected.last());
+        assert_eq!(list.iter().nth(1), expected.get(1));
+        assert_eq!(list.iter().nth_back(0), expected.last());
+    }
+}
+
+#[test]
+fn iter_fused() {
+    for list in representations() {
+        let mut iter = list.iter();
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[test]
+fn iter_double_ended() {
+    let list = OneOrMany::Many(vec![1, 2, 3].into());
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn iter_mut() {
+    for mut list in representations() {
+        let expected = expected(&list)
+            .into_iter()
+            .map(|d| d * 10)
+            .collect::<Vec<_>>();
+
+        let iter = list.iter_mut();
+        assert_eq!(iter.len(), expected.len());
+        iter.for_each(|d| *d *= 10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+
+        for d in &mut list {
+            *d += 1
+        }
+        let mut iter = list.iter_mut().rev();
+        assert_eq!(iter.next().copied(), expected.last().map(|d| d + 1));
+    }
+}
+
+#[test]
+fn into_iter() {
+    for list in representations() {
+        let expected = expected(&list);
+
+        let iter = list.clone().into_iter();
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(iter.as_slice(), expected);
+        assert_eq!(iter.clone().count(), expected.len());
+        assert_eq!(iter.clone().last(), expected.last().copied());
+
+        let reversed = expected.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(list.clone().into_iter().rev().collect::<Vec<_>>(), reversed);
+
+        let folded = list.clone().into_iter().fold(vec![], |mut acc, d| {
+            acc.push(d);
+            acc
+        });
+        assert_eq!(folded, expected);
+
+        let folded = list.clone().into_iter().rfold(vec![], |mut acc, d| {
+            acc.push(d);
+            acc
+        });
+        assert_eq!(folded, reversed);
+
+        for n in 0..4 {
+            assert_eq!(list.clone().into_iter().nth(n), expected.get(n).copied());
+            assert_eq!(
+                list.clone().into_iter().nth_back(n),
+                reversed.get(n).copied()
+            );
+        }
+    }
+}
+
+#[test]
+fn into_iter_fused() {
+    for list in representations() {
+        let mut iter = list.into_iter();
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.as_slice(), &[0; 0]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.nth(1), None);
+    }
+}
+
+#[test]
+fn into_iter_nth_consumes() {
+    let mut iter = OneOrMany::Single(Some(1)).into_iter();
+    assert_eq!(iter.nth(1), None);
+    assert_eq!(iter.len(), 0);
+
+    let mut iter = OneOrMany::Many(vec![1, 2, 3, 4].into()).into_iter();
+    assert_eq!(iter.nth(1), Some(2));
+    assert_eq!(iter.nth_back(0), Some(4));
+    assert_eq!(iter.as_slice(), &[3]);
+}
+
+#[test]
+fn into_iter_collect_preallocates() {
+    let list = OneOrMany::Many((0..100).collect());
+    let collected = list.into_iter().collect::<Vec<_>>();
+    assert_eq!(collected.capacity(), 100);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn map_keeps_variant() {
+    assert!(matches!(
+        OneOrMany::<i32>::Single(None).map(|x| x + 1),
+        OneOrMany::Single(None)
+    ));
+    assert!(matches!(
+        OneOrMany::Single(Some(1)).map(|x| x + 1),
+        OneOrMany::Single(Some(2))
+    ));
+    assert!(matches!(
+        OneOrMany::Many(vec![1].into()).map(|x| x + 1),
+        OneOrMany::Many(v) if v == [2]
+    ));
+    assert!(matches!(
+        OneOrMany::Many(vec![1, 2].into()).map_ref(|x| x * 2),
+        OneOrMany::Many(v) if v == [2, 4]
+    ));
+}
+
+#[test]
+fn try_map() {
+    let parse = |s: &str| s.parse::<i32>();
+
+    assert!(matches!(
+        OneOrMany::Many(vec!["1"].into()).try_map(parse),
+        Ok(OneOrMany::Many(v)) if v == [1]
+    ));
+    assert!(matches!(
+        OneOrMany::Single(Some("1")).try_map(parse),
+        Ok(OneOrMany::Single(Some(1)))
+    ));
+    assert!(OneOrMany::Many(vec!["1", "x"].into())
+        .try_map(parse)
+        .is_err());
+}
+
+#[test]
+fn filter_map() {
+    let even = |x: i32| (x % 2 == 0).then_some(x);
+
+    assert!(matches!(
+        OneOrMany::Single(Some(1)).filter_map(even),
+        OneOrMany::Single(None)
+    ));
+    assert!(matches!(
+        OneOrMany::Many(vec![1, 2, 3].into()).filter_map(even),
+        OneOrMany::Many(v) if v == [2]
+    ));
+}
+
+#[test]
+fn flat_map() {
+    assert!(matches!(
+        OneOrMany::Many(vec![1, 2].into()).flat_map(|x| vec![x; x as usize]),
+        OneOrMany::Many(v) if v == [1, 2, 2]
+    ));
+    assert!(matches!(
+        OneOrMany::Single(Some(1)).flat_map(Some),
+        OneOrMany::Single(Some(1))
+    ));
+    assert_eq!(OneOrMany::Single(Some(2)).flat_map(|x| [x, x]), [2, 2]);
+}
+
+#[test]
+fn borrowing() {
+    let mut list = OneOrMany::Many(vec![String::from("a")].into());
+    assert!(matches!(list.each_ref(), OneOrMany::Many(v) if v == [&"a"]));
+    assert!(matches!(list.as_deref(), OneOrMany::Many(v) if v == ["a"]));
+
+    for item in list.each_mut().into_iter() {
+        item.push('b');
+    }
+    assert!(matches!(list.each_ref().cloned(), OneOrMany::Many(v) if v == ["ab"]));
+
+    let mut list = OneOrMany::Single(Some(1));
+    *list.each_mut().into_single().unwrap() += 1;
+    assert!(matches!(
+        list.each_ref().copied(),
+        OneOrMany::Single(Some(2))
+    ));
+    assert!(matches!(
+        list.each_mut().copied(),
+        OneOrMany::Single(Some(2))
+    ));
+}
+
+#[test]
+fn transpose() {
+    assert!(matches!(
+        OneOrMany::Many(vec![Some(1), Some(2)].into()).transpose(),
+        Some(OneOrMany::Many(v)) if v == [1, 2]
+    ));
+    assert_eq!(
+        OneOrMany::Many(vec![Some(1), None].into()).transpose(),
+        None
+    );
+    assert!(matches!(
+        OneOrMany::<Option<i32>>::Single(None).transpose(),
+        Some(OneOrMany::Single(None))
+    ));
+
+    let results = OneOrMany::Many(vec![Ok(1), Err("a"), Err("b")].into());
+    assert_eq!(results.clone().transpose(), Err("a"));
+    assert!(matches!(
+        results.transpose_all(),
+        Err(OneOrMany::Many(v)) if v == ["a", "b"]
+    ));
+
+    assert!(matches!(
+        OneOrMany::<Result<i32, ()>>::Many(vec![Ok(1)].into()).transpose_all(),
+        Ok(OneOrMany::Many(v)) if v == [1]
+    ));
+    assert!(matches!(
+        OneOrMany::<Result<i32, &str>>::Single(Some(Err("a"))).transpose_all(),
+        Err(OneOrMany::Single(Some("a")))
+    ));
+}
+
+#[test]
+fn collect_results() {
+    let parse = |input: &[&str]| {
+        input
+            .iter()
+            .map(|s| s.parse::<i32>())
+            .collect::<Result<OneOrMany<_>, _>>()
+    };
+
+    assert_eq!(parse(&["1"]).unwrap(), OneOrMany::Single(Some(1)));
+    assert_eq!(parse(&["1", "2"]).unwrap(), [1, 2]);
+    assert!(parse(&["1", "x"]).is_err());
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::representations;
+use one_or_many::{AutoNormalize, OneOrMany};
+
+fn is_normalized<T>(list: &OneOrMany<T>) -> bool {
+    match list {
+        OneOrMany::Single(..) => true,
+        OneOrMany::Many(many) => many.len() > 1,
+    }
+}
+
+#[test]
+fn is_one_is_many_agree_with_len() {
+    for list in representations() {
+        assert_eq!(list.is_one(), list.len() == 1, "{list:?}");
+        assert_eq!(list.is_many(), list.len() > 1, "{list:?}");
+    }
+
+    assert!(OneOrMany::Many(vec![1].into()).is_one());
+    assert!(!OneOrMany::Many(vec![1].into()).is_many());
+    assert!(!OneOrMany::<i32>::Many(vec![].into()).is_many());
+}
+
+#[test]
+fn normalize() {
+    for list in representations() {
+        let mut normalized = list.clone();
+        normalized.normalize();
+
+        assert!(is_normalized(&normalized), "{normalized:?}");
+        assert_eq!(normalized.as_slice(), list.as_slice());
+        assert_eq!(normalized.is_one(), list.is_one());
+        assert_eq!(normalized.is_many(), list.is_many());
+    }
+
+    let mut list = OneOrMany::Many(vec![1].into());
+    list.normalize();
+    assert!(matches!(list, OneOrMany::Single(Some(1))));
+
+    let mut list = OneOrMany::<i32>::Many(vec![].into());
+    list.normalize();
+    assert!(matches!(list, OneOrMany::Single(None)));
+}
+
+#[test]
+fn auto_normalize() {
+    let mut list = AutoNormalize::from(OneOrMany::Many(vec![1].into()));
+    assert!(matches!(*list, OneOrMany::Single(Some(1))));
+
+    list.push(2);
+    list.push(3);
+    assert!(matches!(&*list, OneOrMany::Many(v) if v == &[1, 2, 3]));
+
+    assert_eq!(list.pop(), Some(3));
+    assert!(list.is_many());
+
+    assert_eq!(list.remove(0), 1);
+    assert!(matches!(*list, OneOrMany::Single(Some(2))));
+
+    list.extend([3, 4, 5]);
+    list.retain(|&d| d == 4);
+    assert!(matches!(*list, OneOrMany::Single(Some(4))));
+
+    list.extend([4, 4]);
+    list.dedup();
+    assert!(matches!(*list, OneOrMany::Single(Some(4))));
+
+    list.extend([5, 6]);
+    let split = list.split_off(2);
+    assert!(matches!(&*list, OneOrMany::Many(v) if v == &[4, 5]));
+    assert!(matches!(*split, OneOrMany::Single(Some(6))));
+
+    list.truncate(1);
+    assert!(matches!(*list, OneOrMany::Single(Some(4))));
+
+    list.extend([5, 6]);
+    list.clear();
+    assert!(matches!(*list, OneOrMany::Single(None)));
+}
+
+#[test]
+fn auto_normalize_append() {
+    let mut list = AutoNormalize::<i32>::new();
+    let mut other = AutoNormalize::from(OneOrMany::Many(vec![1, 2].into()));
+    list.append(&mut other);
+
+    assert!(list.is_many());
+    assert!(matches!(*other, OneOrMany::Single(None)));
+
+    let mut list = AutoNormalize::<i32>::new();
+    let mut other = AutoNormalize::from(OneOrMany::Single(Some(1)));
+    list.append(&mut other);
+
+    assert!(matches!(*list, OneOrMany::Single(Some(1))));
+    assert!(matches!(*other, OneOrMany::Single(None)));
+}
+
+#[test]
+fn auto_normalize_compares_like_the_inner() {
+    let one = AutoNormalize::from(OneOrMany::Many(vec![1].into()));
+    assert_eq!(one, AutoNormalize::from(OneOrMany::Single(Some(1))));
+    assert!(one < AutoNormalize::from_iter([1, 2]));
+    assert_eq!(one.cmp(&one.clone()), std::cmp::Ordering::Equal);
+
+    let set = std::collections::HashSet::from([one.clone(), one]);
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn auto_normalize_default() {
+    struct NoDefault;
+
+    let list = AutoNormalize::<NoDefault>::default();
+    assert!(list.is_empty());
+}
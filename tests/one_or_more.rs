@@ -0,0 +1,60 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::{OneOrMany, OneOrMore};
+
+#[test]
+fn never_empty() {
+    let mut list = OneOrMore::new(1);
+    assert_eq!(*list.first(), 1);
+    assert_eq!(*list.last(), 1);
+    assert!(list.is_one());
+
+    list.push(2);
+    list.push(3);
+    assert!(list.is_many());
+    assert_eq!(list.len(), 3);
+    assert_eq!(*list.last(), 3);
+    assert_eq!(list.split_first(), (&1, &[2, 3][..]));
+
+    assert_eq!(list.pop(), Some(3));
+    assert_eq!(list.pop(), Some(2));
+    assert_eq!(list.pop(), None);
+    assert_eq!(list, [1]);
+
+    list.extend([2, 3, 4]);
+    list.truncate(0);
+    assert_eq!(list, [1]);
+}
+
+#[test]
+fn accessors() {
+    let mut list = OneOrMore::new(2);
+    list.extend([5, 1]);
+    *list.first_mut() += 1;
+    *list.last_mut() += 1;
+    list[1] = 4;
+    assert_eq!(list, [3, 4, 2]);
+
+    assert_eq!(list.iter().sum::<i32>(), 9);
+    assert_eq!(list.clone().reduce(|a, b| a * b), 24);
+    assert_eq!(OneOrMore::new(7).reduce(|a, b| a + b), 7);
+}
+
+#[test]
+fn conversions() {
+    assert!(matches!(
+        OneOrMore::<i32>::try_from(OneOrMany::<i32>::Single(None)),
+        Err(OneOrMany::Single(None))
+    ));
+    assert!(OneOrMore::<i32>::try_from(OneOrMany::<i32>::Many(vec![].into())).is_err());
+    assert_eq!(OneOrMore::<i32>::try_from(Vec::<i32>::new()), Err(vec![]));
+
+    let list = OneOrMore::<i32>::try_from(OneOrMany::Many(vec![1, 2].into())).unwrap();
+    assert_eq!(list, OneOrMany::Many(vec![1, 2].into()));
+    assert_eq!(OneOrMany::<i32>::from(list.clone()), [1, 2]);
+    assert_eq!(Vec::from(list), [1, 2]);
+
+    let list = OneOrMore::<i32>::try_from(vec![1]).unwrap();
+    assert_eq!(list.into_one_or_many(), OneOrMany::Single(Some(1)));
+    assert_eq!(OneOrMore::from(1).into_iter().collect::<Vec<_>>(), [1]);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "schemars")]
+
+use one_or_many::{AtMost, Between, Exactly, OneOrMany, OneOrManyInline, OneOrMore};
+use schemars::{schema_for, JsonSchema};
+
+#[test]
+fn one_or_many() {
+    insta::assert_json_snapshot!(schema_for!(OneOrMany<String>));
+    assert_eq!(
+        schema_for!(OneOrManyInline<String, 4>),
+        schema_for!(OneOrMany<String>)
+    );
+}
+
+#[test]
+fn nullable_element() {
+    insta::assert_json_snapshot!(schema_for!(OneOrMany<Option<u32>>));
+}
+
+#[test]
+fn one_or_more() {
+    insta::assert_json_snapshot!(schema_for!(OneOrMore<u32>));
+}
+
+#[test]
+fn bounded() {
+    insta::assert_json_snapshot!("between", schema_for!(Between<u32, 1, 3>));
+    insta::assert_json_snapshot!("at_most", schema_for!(AtMost<u32, 2>));
+    insta::assert_json_snapshot!("exactly", schema_for!(Exactly<u32, 2>));
+}
+
+#[test]
+fn in_struct() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Config {
+        #[serde(default)]
+        hosts: OneOrMany<String>,
+        ports: OneOrMore<u16>,
+    }
+
+    insta::assert_json_snapshot!(schema_for!(Config));
+}
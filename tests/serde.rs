@@ -0,0 +1,371 @@
+#![cfg(feature = "serde")]
+
+use one_or_many::OneOrMany;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Config {
+    hosts: OneOrMany<String>,
+}
+
+fn items<T>(list: OneOrMany<T>) -> Vec<T> {
+    list.into_iter().collect()
+}
+
+#[test]
+fn json_deserialize() {
+    let one: Config = serde_json::from_str(r#"{"hosts": "a"}"#).unwrap();
+    assert!(matches!(&one.hosts, OneOrMany::Single(Some(s)) if s == "a"));
+
+    let many: Config = serde_json::from_str(r#"{"hosts": ["a", "b"]}"#).unwrap();
+    assert!(many.hosts.is_many());
+    assert_eq!(items(many.hosts), ["a", "b"]);
+
+    let null: Config = serde_json::from_str(r#"{"hosts": null}"#).unwrap();
+    assert!(matches!(null.hosts, OneOrMany::Single(None)));
+
+    let missing: Config = serde_json::from_str(r#"{}"#).unwrap();
+    assert!(matches!(missing.hosts, OneOrMany::Single(None)));
+
+    let empty: Config = serde_json::from_str(r#"{"hosts": []}"#).unwrap();
+    assert!(matches!(&empty.hosts, OneOrMany::Many(v) if v.is_empty()));
+}
+
+#[test]
+fn json_deserialize_error() {
+    // the error of the element is kept, rather than a generic "expected one or many"
+    let err = serde_json::from_str::<Config>(r#"{"hosts": {"a": 1}}"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: map, expected a string at line 1 column 11"
+    );
+
+    let err = serde_json::from_str::<OneOrMany<u32>>(r#"[1, "two"]"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"invalid type: string "two", expected u32 at line 1 column 9"#
+    );
+
+    let err = serde_json::from_str::<OneOrMany<u32>>(r#""x""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"invalid type: string "x", expected u32 at line 1 column 3"#
+    );
+
+    let err = toml::from_str::<Config>("hosts = [1]").unwrap_err();
+    assert!(err.to_string().contains("expected a string"), "{err}");
+}
+
+#[test]
+fn json_deserialize_struct() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Host {
+        name: String,
+    }
+
+    let one: OneOrMany<Host> = serde_json::from_str(r#"{"name": "a"}"#).unwrap();
+    assert!(one.is_one());
+    assert_eq!(one[0].name, "a");
+
+    let many: OneOrMany<Host> = serde_json::from_str(r#"[{"name": "a"}, {"name": "b"}]"#).unwrap();
+    assert!(many.is_many());
+    assert_eq!(many[1].name, "b");
+
+    let err = serde_json::from_str::<OneOrMany<Host>>(r#"[{"name": "a"}, {}]"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `name`"), "{err}");
+}
+
+#[test]
+fn json_serialize() {
+    let to_json = |hosts| serde_json::to_string(&Config { hosts }).unwrap();
+
+    assert_eq!(to_json(OneOrMany::new()), r#"{"hosts":[]}"#);
+    assert_eq!(
+        to_json(OneOrMany::from("a".to_string())),
+        r#"{"hosts":"a"}"#
+    );
+    assert_eq!(
+        to_json(OneOrMany::from(vec!["a".to_string()])),
+        r#"{"hosts":"a"}"#
+    );
+    assert_eq!(
+        to_json(OneOrMany::from(vec!["a".to_string(), "b".to_string()])),
+        r#"{"hosts":["a","b"]}"#
+    );
+}
+
+#[test]
+fn json_nested() {
+    let list: OneOrMany<OneOrMany<u32>> = serde_json::from_str("[1, [2, 3]]").unwrap();
+    let list = items(list).into_iter().map(items).collect::<Vec<_>>();
+    assert_eq!(list, [vec![1], vec![2, 3]]);
+}
+
+#[test]
+fn toml_round_trip() {
+    let one: Config = toml::from_str(r#"hosts = "a""#).unwrap();
+    assert!(one.hosts.is_one());
+    assert_eq!(toml::to_string(&one).unwrap().trim(), r#"hosts = "a""#);
+
+    let many: Config = toml::from_str(r#"hosts = ["a", "b"]"#).unwrap();
+    assert!(many.hosts.is_many());
+    assert_eq!(
+        toml::to_string(&many).unwrap().trim(),
+        r#"hosts = ["a", "b"]"#
+    );
+
+    let missing: Config = toml::from_str("").unwrap();
+    assert!(matches!(missing.hosts, OneOrMany::Single(None)));
+}
+
+#[test]
+fn yaml_round_trip() {
+    let one: Config = serde_yaml::from_str("hosts: a").unwrap();
+    assert!(one.hosts.is_one());
+    assert_eq!(serde_yaml::to_string(&one).unwrap(), "hosts: a\n");
+
+    let many: Config = serde_yaml::from_str("hosts:\n- a\n- b\n").unwrap();
+    assert!(many.hosts.is_many());
+    assert_eq!(serde_yaml::to_string(&many).unwrap(), "hosts:\n- a\n- b\n");
+
+    let null: Config = serde_yaml::from_str("hosts: ~").unwrap();
+    assert!(matches!(null.hosts, OneOrMany::Single(None)));
+
+    let missing: Config = serde_yaml::from_str("{}").unwrap();
+    assert!(matches!(missing.hosts, OneOrMany::Single(None)));
+}
+
+#[derive(Serialize, Deserialize)]
+struct Policies {
+    #[serde(with = "one_or_many::serde::always_seq")]
+    always_seq: OneOrMany<u32>,
+    #[serde(with = "one_or_many::serde::scalar_if_one")]
+    scalar_if_one: OneOrMany<u32>,
+    #[serde(with = "one_or_many::serde::preserve", default)]
+    preserve: OneOrMany<u32>,
+}
+
+#[test]
+fn policies_serialize() {
+    let to_json = |list: fn() -> OneOrMany<u32>| {
+        serde_json::to_value(Policies {
+            always_seq: list(),
+            scalar_if_one: list(),
+            preserve: list(),
+        })
+        .unwrap()
+    };
+
+    assert_eq!(
+        to_json(OneOrMany::new),
+        serde_json::json!({"always_seq": [], "scalar_if_one": [], "preserve": null})
+    );
+    assert_eq!(
+        to_json(|| OneOrMany::from(1)),
+        serde_json::json!({"always_seq": [1], "scalar_if_one": 1, "preserve": 1})
+    );
+    assert_eq!(
+        to_json(|| OneOrMany::from(vec![1])),
+        serde_json::json!({"always_seq": [1], "scalar_if_one": 1, "preserve": [1]})
+    );
+    assert_eq!(
+        to_json(|| OneOrMany::from(vec![1, 2])),
+        serde_json::json!({"always_seq": [1, 2], "scalar_if_one": [1, 2], "preserve": [1, 2]})
+    );
+}
+
+#[test]
+fn policies_deserialize() {
+    let policies: Policies =
+        serde_json::from_str(r#"{"always_seq": 1, "scalar_if_one": [1, 2]}"#).unwrap();
+    assert!(policies.always_seq.is_one());
+    assert!(policies.scalar_if_one.is_many());
+    assert!(matches!(policies.preserve, OneOrMany::Single(None)));
+}
+
+#[test]
+fn preserve_round_trip() {
+    for input in [
+        r#"{"always_seq":[],"scalar_if_one":[],"preserve":null}"#,
+        r#"{"always_seq":[],"scalar_if_one":[],"preserve":1}"#,
+        r#"{"always_seq":[],"scalar_if_one":[],"preserve":[]}"#,
+        r#"{"always_seq":[],"scalar_if_one":[],"preserve":[1]}"#,
+        r#"{"always_seq":[],"scalar_if_one":[],"preserve":[1,2]}"#,
+    ] {
+        let policies: Policies = serde_json::from_str(input).unwrap();
+        assert_eq!(serde_json::to_string(&policies).unwrap(), input);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Preserve {
+        #[serde(with = "one_or_many::serde::preserve")]
+        hosts: OneOrMany<String>,
+    }
+
+    for input in ["hosts: a\n", "hosts:\n- a\n", "hosts:\n- a\n- b\n"] {
+        let config: Preserve = serde_yaml::from_str(input).unwrap();
+        assert_eq!(serde_yaml::to_string(&config).unwrap(), input);
+    }
+}
+
+#[test]
+fn inline_format() {
+    use one_or_many::OneOrManyInline;
+
+    let list: OneOrManyInline<u32, 2> = serde_json::from_str("1").unwrap();
+    assert_eq!(list, [1]);
+    assert_eq!(serde_json::to_string(&list).unwrap(), "1");
+
+    let list: OneOrManyInline<u32, 2> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3]");
+
+    let list: OneOrManyInline<u32, 2> = serde_json::from_str("null").unwrap();
+    assert_eq!(serde_json::to_string(&list).unwrap(), "[]");
+}
+
+#[test]
+fn one_or_more_format() {
+    use one_or_many::OneOrMore;
+
+    let list: OneOrMore<u32> = serde_json::from_str("1").unwrap();
+    assert_eq!(list, [1]);
+    assert_eq!(serde_json::to_string(&list).unwrap(), "1");
+
+    let list: OneOrMore<u32> = serde_json::from_str("[1, 2]").unwrap();
+    assert_eq!(list, [1, 2]);
+    assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2]");
+
+    let err = serde_json::from_str::<OneOrMore<u32>>("[]").unwrap_err();
+    assert!(err.to_string().contains("at least one value"), "{err}");
+
+    let err = serde_json::from_str::<OneOrMore<u32>>("null").unwrap_err();
+    assert!(err.to_string().contains("at least one value"), "{err}");
+
+    #[derive(Debug, Deserialize)]
+    struct Required {
+        #[allow(dead_code)]
+        hosts: OneOrMore<String>,
+    }
+
+    let err = serde_json::from_str::<Required>("{}").unwrap_err();
+    assert!(err.to_string().contains("at least one value"), "{err}");
+}
+
+#[test]
+fn bounded_format() {
+    use one_or_many::{AtMost, Between};
+
+    #[derive(Debug, Deserialize)]
+    struct Hosts {
+        #[allow(dead_code)]
+        hosts: Between<String, 1, 3>,
+    }
+
+    let list: Between<u32, 1, 3> = serde_json::from_str("1").unwrap();
+    assert_eq!(list, [1]);
+    assert_eq!(serde_json::to_string(&list).unwrap(), "1");
+
+    let list: AtMost<u32, 2> = serde_json::from_str("null").unwrap();
+    assert!(list.is_empty());
+
+    let err = serde_json::from_str::<Hosts>(r#"{"hosts": ["a", "b", "c", "d"]}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected between 1 and 3 elements, found 4"),
+        "{err}"
+    );
+
+    let err = serde_json::from_str::<Hosts>("{}").unwrap_err();
+    assert!(err.to_string().contains("found 0"), "{err}");
+}
+
+#[test]
+fn delimited() {
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Env {
+        #[serde(with = "one_or_many::serde::delimited::comma", default)]
+        ports: OneOrMany<u16>,
+        #[serde(with = "one_or_many::serde::delimited::whitespace", default)]
+        hosts: OneOrMany<String>,
+        #[serde(
+            deserialize_with = "one_or_many::serde::delimited::split::<';', _, _>",
+            default
+        )]
+        paths: OneOrMany<String>,
+    }
+
+    let env: Env = serde_json::from_str(
+        r#"{"ports": "80, 443,,", "hosts": "  a  b\\ c ", "paths": "x\\;y; z"}"#,
+    )
+    .unwrap();
+    assert!(env.ports.is_many());
+    assert_eq!(env.ports, [80, 443]);
+    assert_eq!(env.hosts, ["a", "b c"]);
+    assert_eq!(env.paths, ["x;y", "z"]);
+
+    let env: Env = serde_json::from_str(r#"{"ports": 80, "hosts": ["a b"]}"#).unwrap();
+    assert!(matches!(env.ports, OneOrMany::Single(Some(80))));
+    assert_eq!(env.hosts, ["a b"]);
+    assert!(env.paths.is_empty());
+
+    let env: Env = serde_json::from_str(r#"{"ports": "80", "hosts": ""}"#).unwrap();
+    assert!(matches!(env.ports, OneOrMany::Single(Some(80))));
+    assert!(matches!(env.hosts, OneOrMany::Single(None)));
+    assert_eq!(
+        serde_json::to_string(&env).unwrap(),
+        r#"{"ports":80,"hosts":[],"paths":[]}"#
+    );
+
+    let err = serde_json::from_str::<Env>(r#"{"ports": "80,http"}"#).unwrap_err();
+    assert!(
+        err.to_string().contains(r#"invalid element 1 ("http")"#),
+        "{err}"
+    );
+}
+
+#[test]
+fn delimited_derived_enum() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        Debug,
+        Info,
+        #[serde(alias = "warning")]
+        Warn,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Env {
+        #[serde(with = "one_or_many::serde::delimited::comma")]
+        levels: OneOrMany<Level>,
+        #[serde(with = "one_or_many::serde::delimited::comma", default)]
+        flags: OneOrMany<Option<bool>>,
+    }
+
+    let env: Env =
+        serde_json::from_str(r#"{"levels": "debug, warning", "flags": "true,false"}"#).unwrap();
+    assert_eq!(env.levels, [Level::Debug, Level::Warn]);
+    assert_eq!(env.flags, [Some(true), Some(false)]);
+
+    let env: Env = serde_json::from_str(r#"{"levels": ["info"]}"#).unwrap();
+    assert_eq!(env.levels, [Level::Info]);
+
+    let err = serde_json::from_str::<Env>(r#"{"levels": "info,Debug"}"#).unwrap_err();
+    assert!(
+        err.to_string().contains(r#"invalid element 1 ("Debug")"#),
+        "{err}"
+    );
+}
+
+#[test]
+fn delimited_trailing_escape() {
+    #[derive(Deserialize)]
+    struct Paths {
+        #[serde(with = "one_or_many::serde::delimited::comma")]
+        paths: OneOrMany<String>,
+    }
+
+    let paths: Paths = serde_json::from_str(r#"{"paths": "a\\, b\\ ,c\\"}"#).unwrap();
+    assert_eq!(paths.paths, ["a, b ", "c\\"]);
+}
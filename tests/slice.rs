@@ -0,0 +1,59 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::{Borrow, BorrowMut};
+
+use one_or_many::OneOrMany;
+
+#[test]
+fn as_slice() {
+    assert_eq!(OneOrMany::<i32>::Single(None).as_slice(), &[0; 0]);
+    assert_eq!(OneOrMany::Single(Some(1)).as_slice(), &[1]);
+    assert_eq!(OneOrMany::<i32>::Many(vec![].into()).as_slice(), &[0; 0]);
+    assert_eq!(OneOrMany::Many(vec![1, 2].into()).as_slice(), &[1, 2]);
+
+    let mut list = OneOrMany::Single(Some(1));
+    list.as_mut_slice()[0] = 2;
+    assert_eq!(list.as_slice(), &[2]);
+
+    let mut list = OneOrMany::Many(vec![1, 2].into());
+    list.as_mut_slice().swap(0, 1);
+    assert_eq!(list.as_slice(), &[2, 1]);
+}
+
+#[test]
+fn slice_api() {
+    let mut list = OneOrMany::Many(vec![3, 1, 2].into());
+    list.sort();
+    assert_eq!(&*list, &[1, 2, 3]);
+    assert_eq!(list.binary_search(&2), Ok(1));
+    assert!(list.contains(&3));
+    assert_eq!(list.first(), Some(&1));
+    assert_eq!(list.last(), Some(&3));
+    assert_eq!(list.windows(2).count(), 2);
+
+    let mut list = OneOrMany::Single(Some(1));
+    list.reverse();
+    assert_eq!(list.first(), list.last());
+    assert_eq!(list.windows(2).count(), 0);
+    assert!(list.starts_with(&[1]));
+
+    let list = OneOrMany::<i32>::new();
+    assert_eq!(list.first(), None);
+    assert!(!list.contains(&1));
+}
+
+#[test]
+fn as_ref_borrow() {
+    fn sum(list: impl AsRef<[i32]>) -> i32 {
+        list.as_ref().iter().sum()
+    }
+
+    assert_eq!(sum(OneOrMany::Single(Some(1))), 1);
+    assert_eq!(sum(OneOrMany::Many(vec![1, 2].into())), 3);
+
+    let mut list = OneOrMany::Many(vec![1, 2].into());
+    list.as_mut()[1] = 3;
+    <OneOrMany<_> as BorrowMut<[i32]>>::borrow_mut(&mut list)[0] = 0;
+    let slice: &[i32] = list.borrow();
+    assert_eq!(slice, &[0, 3]);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "utoipa")]
+
+use one_or_many::{Between, OneOrMany, OneOrMore};
+use utoipa::{PartialSchema, ToSchema};
+
+#[test]
+fn one_or_many() {
+    insta::assert_json_snapshot!(OneOrMany::<String>::schema());
+}
+
+#[test]
+fn nullable_element() {
+    insta::assert_json_snapshot!(OneOrMany::<Option<u32>>::schema());
+}
+
+#[test]
+fn one_or_more() {
+    insta::assert_json_snapshot!(OneOrMore::<u32>::schema());
+}
+
+#[test]
+fn between() {
+    insta::assert_json_snapshot!(Between::<u32, 1, 3>::schema());
+}
+
+#[test]
+fn in_struct() {
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(dead_code)]
+    struct Request {
+        hosts: OneOrMany<String>,
+        pets: OneOrMore<Pet>,
+    }
+
+    insta::assert_json_snapshot!(Request::schema());
+
+    let mut schemas = Vec::new();
+    Request::schemas(&mut schemas);
+    insta::assert_json_snapshot!("in_struct_components", schemas);
+}
+
+#[test]
+fn names() {
+    assert_eq!(OneOrMany::<String>::name(), "OneOrMany_for_String");
+    assert_eq!(OneOrMore::<u32>::name(), "OneOrMore_for_u32");
+    assert_eq!(Between::<u32, 1, 3>::name(), "Between_1_and_3_of_u32");
+
+    let components = utoipa::openapi::ComponentsBuilder::new()
+        .schema_from::<OneOrMany<u32>>()
+        .schema_from::<OneOrMany<String>>()
+        .build();
+    assert_eq!(components.schemas.len(), 2);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::{apply, op, representation};
+use one_or_many::OneOrMany;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn matches_vec(list in representation(), ops in prop::collection::vec(op(), 0..32)) {
+        let mut list = list;
+        let mut model = list.as_slice().to_vec();
+
+        for op in &ops {
+            let (left, right) = apply(op, &mut list, &mut model);
+            prop_assert_eq!(left, right, "{:?}", op);
+            prop_assert_eq!(list.as_slice(), model.as_slice(), "{:?}", op);
+            prop_assert_eq!(list.len(), model.len());
+        }
+    }
+
+    #[test]
+    fn append_empties_other(left in representation(), right in representation()) {
+        let (mut left, mut right) = (left, right);
+        let expected = [left.as_slice(), right.as_slice()].concat();
+        left.append(&mut right);
+        prop_assert_eq!(left.as_slice(), expected.as_slice());
+        prop_assert_eq!(right.len(), 0);
+    }
+}
+
+#[test]
+fn single_transitions() {
+    let mut list = OneOrMany::new();
+    list.insert(0, 2);
+    assert!(matches!(list, OneOrMany::Single(Some(2))));
+
+    list.insert(0, 1);
+    assert!(matches!(&list, OneOrMany::Many(v) if v == &[1, 2]));
+
+    let mut list = OneOrMany::Single(Some(1));
+    assert_eq!(list.remove(0), 1);
+    assert!(matches!(list, OneOrMany::Single(None)));
+
+    let mut list = OneOrMany::Single(Some(1));
+    let split = list.split_off(0);
+    assert!(matches!(list, OneOrMany::Single(None)));
+    assert!(matches!(split, OneOrMany::Single(Some(1))));
+
+    let mut list = OneOrMany::Single(Some(1));
+    assert_eq!(list.drain(1..).count(), 0);
+    assert_eq!(list.drain(..).collect::<Vec<_>>(), [1]);
+    assert!(matches!(list, OneOrMany::Single(None)));
+
+    let mut list = OneOrMany::Single(Some(1));
+    list.append(&mut OneOrMany::Single(Some(2)));
+    assert!(matches!(&list, OneOrMany::Many(v) if v == &[1, 2]));
+}
+
+#[test]
+#[should_panic = "removal index (is 1) should be < len (is 1)"]
+fn remove_out_of_bounds() {
+    OneOrMany::Single(Some(1)).remove(1);
+}
+
+#[test]
+#[should_panic = "swap_remove index (is 0) should be < len (is 0)"]
+fn swap_remove_out_of_bounds() {
+    OneOrMany::<i32>::Single(None).swap_remove(0);
+}
+
+#[test]
+#[should_panic = "insertion index (is 2) should be <= len (is 1)"]
+fn insert_out_of_bounds() {
+    OneOrMany::Single(Some(1)).insert(2, 2);
+}
+
+#[test]
+#[should_panic = "range end index 2 out of range for slice of length 1"]
+fn drain_out_of_bounds() {
+    OneOrMany::Single(Some(1)).drain(..2);
+}
+
+#[test]
+#[should_panic = "`at` split index (is 2) should be <= len (is 1)"]
+fn split_off_out_of_bounds() {
+    OneOrMany::Single(Some(1)).split_off(2);
+}
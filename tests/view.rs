@@ -0,0 +1,52 @@
+#![cfg(feature = "alloc")]
+
+use one_or_many::{OneOrMany, OwnedView, View, ViewMut};
+
+#[test]
+fn view_by_cardinality() {
+    assert_eq!(OneOrMany::<i32>::Single(None).view(), View::Empty);
+    assert_eq!(OneOrMany::<i32>::Many(vec![].into()).view(), View::Empty);
+    assert_eq!(OneOrMany::Single(Some(1)).view(), View::One(&1));
+    assert_eq!(OneOrMany::Many(vec![1].into()).view(), View::One(&1));
+    assert_eq!(
+        OneOrMany::Many(vec![1, 2].into()).view(),
+        View::Many(&[1, 2])
+    );
+}
+
+#[test]
+fn view_mut() {
+    let mut list = OneOrMany::Many(vec![1].into());
+    if let ViewMut::One(one) = list.view_mut() {
+        *one = 2;
+    }
+    assert_eq!(list, [2]);
+
+    list.push(3);
+    match list.view_mut() {
+        ViewMut::Many(many) => many.reverse(),
+        _ => unreachable!(),
+    }
+    assert_eq!(list, [3, 2]);
+}
+
+#[test]
+fn into_view() {
+    assert_eq!(
+        OneOrMany::<i32>::Many(vec![].into()).into_view(),
+        OwnedView::Empty
+    );
+    assert_eq!(
+        OneOrMany::Many(vec![1].into()).into_view(),
+        OwnedView::One(1)
+    );
+    assert_eq!(
+        OneOrMany::Many(vec![1, 2].into()).into_view(),
+        OwnedView::Many(vec![1, 2].into())
+    );
+
+    assert!(matches!(
+        OneOrMany::from(OwnedView::One(1)),
+        OneOrMany::Single(Some(1))
+    ));
+}